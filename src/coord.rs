@@ -1,14 +1,47 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::FRAC_PI_2;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Neg, Sub};
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
+
+/// Mean radius of the WGS 84 ellipsoid in meters.
+pub const EARTH_MEAN_RADIUS: f64 = 6_371_008.8;
+
+/// Semi-major axis of the WGS 84 ellipsoid in meters.
+pub const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+
+/// Flattening of the WGS 84 ellipsoid.
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
 
 /// WGS 84 longitude/latitude pair.
+///
+/// Constructors and tuple conversions take the longitude first, matching x/y order as in
+/// GeoJSON and WKT. Only the text form of `Display` and `FromStr` is `lat,lon`.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "RawGeoCoord"))]
 pub struct GeoCoord {
     lon: f64,
     lat: f64,
 }
 
 impl GeoCoord {
+    /// Coordinate from a longitude within ±180° and a latitude within ±90°, the same as
+    /// `from_degrees`.
+    pub fn new(lon: f64, lat: f64) -> Result<Self, InvalidGeoCoord> {
+        GeoCoord::from_degrees(lon, lat)
+    }
+
     pub fn from_degrees(lon: f64, lat: f64) -> Result<Self, InvalidGeoCoord> {
         if (-180.0..=180.0).contains(&lon) && (-90.0..=90.0).contains(&lat) {
             Ok(Self { lon, lat })
@@ -17,12 +50,49 @@ impl GeoCoord {
         }
     }
 
+    /// Like `from_degrees`, but wraps longitudes outside ±180° around the globe first, so 190°
+    /// becomes -170° and 540° becomes 180°. Latitudes are still validated as is.
+    pub fn from_degrees_normalized(lon: f64, lat: f64) -> Result<Self, InvalidGeoCoord> {
+        let lon = if lon > 180.0 { 180.0 - (180.0 - lon).rem_euclid(360.0) } else { wrap_lon(lon) };
+
+        Self::from_degrees(lon, lat)
+    }
+
+    /// Coordinate with the longitude clamped to ±180° and the latitude to ±90°.
+    ///
+    /// Never fails: infinities are clamped like any other value, while a NaN component is
+    /// replaced by 0.
+    pub fn clamped(lon: f64, lat: f64) -> Self {
+        let clamp = |value: f64, limit: f64| if value.is_nan() { 0.0 } else { value.clamp(-limit, limit) };
+
+        Self { lon: clamp(lon, 180.0), lat: clamp(lat, 90.0) }
+    }
+
+    pub fn from_radians(lon: f64, lat: f64) -> Result<Self, InvalidGeoCoord> {
+        Self::from_degrees(lon.to_degrees(), lat.to_degrees())
+    }
+
+    /// Coordinate from integer nanodegrees, as produced by `to_nanodegrees`.
+    ///
+    /// Valid inputs are within ±180_000_000_000 for the longitude and ±90_000_000_000 for the
+    /// latitude, well below the 2^53 up to which `f64` represents integers exactly. Anything
+    /// beyond is rejected.
     pub fn from_nanodegrees(lon: i64, lat: i64) -> Result<Self, InvalidGeoCoord> {
         Self::from_degrees(lon as f64 / 1_000_000_000.0, lat as f64 / 1_000_000_000.0)
     }
 
     pub fn to_nanodegrees(&self) -> (i64, i64) {
-        ((self.lon * 1_000_000_000f64).floor() as i64, (self.lat * 1_000_000_000f64).floor() as i64)
+        ((self.lon * 1_000_000_000f64).round() as i64, (self.lat * 1_000_000_000f64).round() as i64)
+    }
+
+    /// Coordinate from single precision degrees, e.g. read back from a GPU buffer.
+    pub fn from_f32(lon: f32, lat: f32) -> Result<Self, InvalidGeoCoord> {
+        Self::from_degrees(f64::from(lon), f64::from(lat))
+    }
+
+    /// Longitude and latitude rounded to single precision, which is accurate to about a meter.
+    pub fn to_f32_pair(&self) -> (f32, f32) {
+        (self.lon as f32, self.lat as f32)
     }
 
     pub fn lon(&self) -> f64 { 
@@ -32,22 +102,478 @@ impl GeoCoord {
     pub fn lat(&self) -> f64 { 
         self.lat 
     }
+
+    pub fn lon_rad(&self) -> f64 {
+        self.lon.to_radians()
+    }
+
+    pub fn lat_rad(&self) -> f64 {
+        self.lat.to_radians()
+    }
+
+    /// Whether the coordinate is the north or south pole, where every longitude denotes the
+    /// same point.
+    pub fn is_pole(&self) -> bool {
+        self.lat.abs() == 90.0
+    }
+
+    /// Whether the coordinate lies on the antimeridian, given either as -180° or 180°.
+    pub fn is_on_dateline(&self) -> bool {
+        self.lon.abs() == 180.0
+    }
+
+    /// Whether both coordinates are within `epsilon_deg` degrees of each other on each axis.
+    ///
+    /// Like `==`, longitudes are compared across the dateline and ignored if both coordinates
+    /// are (within the tolerance) at the same pole.
+    pub fn approx_eq(&self, other: &GeoCoord, epsilon_deg: f64) -> bool {
+        if (self.lat - other.lat).abs() > epsilon_deg {
+            return false;
+        }
+
+        if self.lat.abs() >= 90.0 - epsilon_deg && other.lat.abs() >= 90.0 - epsilon_deg {
+            return true;
+        }
+
+        let dlon = (self.lon - other.lon).rem_euclid(360.0);
+
+        dlon.min(360.0 - dlon) <= epsilon_deg
+    }
+
+    /// Great-circle distance in meters on a sphere of `EARTH_MEAN_RADIUS`.
+    pub fn haversine_distance(&self, other: &GeoCoord) -> f64 {
+        self.haversine_distance_with_radius(other, EARTH_MEAN_RADIUS)
+    }
+
+    /// Great-circle distance on a sphere of the given radius, in the unit of the radius.
+    pub fn haversine_distance_with_radius(&self, other: &GeoCoord, radius: f64) -> f64 {
+        let lat_a = self.lat_rad();
+        let lat_b = other.lat_rad();
+
+        let sin_dlat = ((other.lat - self.lat).to_radians() / 2.0).sin();
+        let sin_dlon = ((other.lon - self.lon).to_radians() / 2.0).sin();
+
+        let a = (sin_dlat * sin_dlat + lat_a.cos() * lat_b.cos() * sin_dlon * sin_dlon).clamp(0.0, 1.0);
+
+        // atan2 stays well-conditioned both for near-identical and for antipodal points
+        2.0 * a.sqrt().atan2((1.0 - a).sqrt()) * radius
+    }
+
+    /// Ellipsoidal distance in meters on WGS 84, see `vincenty_inverse`.
+    pub fn vincenty_distance(&self, other: &GeoCoord) -> Result<f64, VincentyError> {
+        self.vincenty_inverse(other).map(|(distance, _, _)| distance)
+    }
+
+    /// Solves the inverse geodetic problem on WGS 84 using Vincenty's formulae.
+    ///
+    /// Returns the distance in meters, the forward azimuth at `self` and the back azimuth at
+    /// `other` (pointing back towards `self`), both in degrees within `0..360`.
+    pub fn vincenty_inverse(&self, other: &GeoCoord) -> Result<(f64, f64, f64), VincentyError> {
+        let a = WGS84_SEMI_MAJOR_AXIS;
+        let f = WGS84_FLATTENING;
+        let b = a * (1.0 - f);
+
+        let l = (other.lon - self.lon).to_radians();
+        let u1 = ((1.0 - f) * self.lat_rad().tan()).atan();
+        let u2 = ((1.0 - f) * other.lat_rad().tan()).atan();
+
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        let mut iterations = 0;
+
+        let (sin_sigma, cos_sigma, sigma, cos_sq_alpha, cos_2sigma_m, sin_lambda, cos_lambda) = loop {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2) + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)).sqrt();
+
+            if sin_sigma == 0.0 {
+                // Coincident points
+                return Ok((0.0, 0.0, 0.0));
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+
+            // Both points on the equator
+            let cos_2sigma_m = if cos_sq_alpha != 0.0 { cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha } else { 0.0 };
+
+            let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+
+            let prev_lambda = lambda;
+            lambda = l + (1.0 - c) * f * sin_alpha * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+
+            if (lambda - prev_lambda).abs() <= 1e-12 {
+                break (sin_sigma, cos_sigma, sigma, cos_sq_alpha, cos_2sigma_m, sin_lambda, cos_lambda);
+            }
+
+            iterations += 1;
+
+            if iterations >= 200 || lambda.abs() > core::f64::consts::PI * 2.0 {
+                return Err(VincentyError::NotConverged);
+            }
+        };
+
+        let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+        let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+
+        let delta_sigma = big_b * sin_sigma * (cos_2sigma_m + big_b / 4.0 * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+            - big_b / 6.0 * cos_2sigma_m * (-3.0 + 4.0 * sin_sigma * sin_sigma) * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+
+        let distance = b * big_a * (sigma - delta_sigma);
+
+        let alpha_1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        let alpha_2 = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+
+        let forward = normalize_bearing(alpha_1.to_degrees());
+        let back = normalize_bearing(alpha_2.to_degrees() + 180.0);
+
+        Ok((distance, forward, back))
+    }
+
+    /// Initial great-circle bearing towards `other` in degrees within `0..360`.
+    ///
+    /// Leaving the north pole every direction is south (180), leaving the south pole every
+    /// direction is north (0). The bearing to a coincident point is 0.
+    pub fn bearing_to(&self, other: &GeoCoord) -> f64 {
+        if self.lat == 90.0 {
+            return 180.0;
+        } else if self.lat == -90.0 {
+            return 0.0;
+        }
+
+        let lat_a = self.lat_rad();
+        let lat_b = other.lat_rad();
+        let dlon = (other.lon - self.lon).to_radians();
+
+        let y = dlon.sin() * lat_b.cos();
+        let x = lat_a.cos() * lat_b.sin() - lat_a.sin() * lat_b.cos() * dlon.cos();
+
+        normalize_bearing(y.atan2(x).to_degrees())
+    }
+
+    /// Great-circle bearing at `other` when arriving from `self`, in degrees within `0..360`.
+    pub fn final_bearing_to(&self, other: &GeoCoord) -> f64 {
+        normalize_bearing(other.bearing_to(self) + 180.0)
+    }
+
+    /// Point reached by travelling `distance_m` meters along the great circle leaving at the
+    /// given initial bearing, on a sphere of `EARTH_MEAN_RADIUS`.
+    ///
+    /// A NaN or infinite bearing or distance leaves the coordinate unchanged.
+    pub fn destination(&self, bearing_deg: f64, distance_m: f64) -> GeoCoord {
+        if !bearing_deg.is_finite() || !distance_m.is_finite() {
+            return *self;
+        }
+
+        let angular_distance = distance_m / EARTH_MEAN_RADIUS;
+        let bearing = bearing_deg.to_radians();
+
+        let lat_a = self.lat_rad();
+        let lon_a = self.lon_rad();
+
+        let sin_lat_b = lat_a.sin() * angular_distance.cos() + lat_a.cos() * angular_distance.sin() * bearing.cos();
+        let lat_b = sin_lat_b.clamp(-1.0, 1.0).asin();
+
+        let y = bearing.sin() * angular_distance.sin() * lat_a.cos();
+        let x = angular_distance.cos() - lat_a.sin() * sin_lat_b;
+        let lon_b = lon_a + y.atan2(x);
+
+        GeoCoord {
+            lon: wrap_lon(lon_b.to_degrees()),
+            lat: lat_b.to_degrees().clamp(-90.0, 90.0),
+        }
+    }
+
+    /// The point on the opposite side of the globe.
+    pub fn antipode(&self) -> GeoCoord {
+        let lon = if self.lon <= 0.0 { self.lon + 180.0 } else { self.lon - 180.0 };
+
+        GeoCoord { lon, lat: -self.lat }
+    }
+
+    /// Half-way point along the great circle between `self` and `other`.
+    pub fn midpoint(&self, other: &GeoCoord) -> GeoCoord {
+        let lat_a = self.lat_rad();
+        let lat_b = other.lat_rad();
+        let dlon = (other.lon - self.lon).to_radians();
+
+        let bx = lat_b.cos() * dlon.cos();
+        let by = lat_b.cos() * dlon.sin();
+
+        let lat_m = (lat_a.sin() + lat_b.sin()).atan2(((lat_a.cos() + bx).powi(2) + by * by).sqrt());
+        let lon_m = self.lon_rad() + by.atan2(lat_a.cos() + bx);
+
+        GeoCoord {
+            lon: wrap_lon(lon_m.to_degrees()),
+            lat: lat_m.to_degrees().clamp(-90.0, 90.0),
+        }
+    }
+
+    /// `segments + 1` points evenly spaced along the great circle from `self` to `other`,
+    /// including both end points.
+    ///
+    /// Zero segments yields just `self`.
+    pub fn great_circle_path(&self, other: &GeoCoord, segments: usize) -> Vec<GeoCoord> {
+        if segments == 0 {
+            return vec![*self];
+        }
+
+        let angular_distance = self.haversine_distance_with_radius(other, 1.0);
+
+        (0..=segments)
+            .map(|i| self.interpolate(other, angular_distance, i as f64 / segments as f64))
+            .collect()
+    }
+
+    /// Point at fraction `t` in `0..=1` of the way along the great circle from `self` to
+    /// `other`.
+    ///
+    /// For (nearly) antipodal points any great circle is a shortest path; the one leaving
+    /// `self` at `bearing_to(other)` is used.
+    pub fn slerp(&self, other: &GeoCoord, t: f64) -> GeoCoord {
+        if t <= 0.0 {
+            return *self;
+        } else if t >= 1.0 {
+            return *other;
+        }
+
+        self.interpolate(other, self.haversine_distance_with_radius(other, 1.0), t)
+    }
+
+    /// Centroid of `coords` on the unit sphere, found by averaging their direction vectors.
+    ///
+    /// Unlike a plain lon/lat mean this is correct across the antimeridian. Returns `None`
+    /// for an empty slice or when the points cancel out, e.g. a pair of antipodes.
+    pub fn centroid(coords: &[GeoCoord]) -> Option<GeoCoord> {
+        if coords.is_empty() {
+            return None;
+        }
+
+        let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+
+        for coord in coords {
+            let (sin_lat, cos_lat) = coord.lat_rad().sin_cos();
+            let (sin_lon, cos_lon) = coord.lon_rad().sin_cos();
+
+            x += cos_lat * cos_lon;
+            y += cos_lat * sin_lon;
+            z += sin_lat;
+        }
+
+        let n = coords.len() as f64;
+        let (x, y, z) = (x / n, y / n, z / n);
+
+        if (x * x + y * y + z * z).sqrt() < 1e-12 {
+            return None;
+        }
+
+        Some(GeoCoord {
+            lon: wrap_lon(y.atan2(x).to_degrees()),
+            lat: z.atan2(x.hypot(y)).to_degrees().clamp(-90.0, 90.0),
+        })
+    }
+
+    /// Signed distance in meters from `self` to the great circle running from `start`
+    /// through `end`, on a sphere of `EARTH_MEAN_RADIUS`.
+    ///
+    /// The result is positive when `self` lies to the right of the path as seen travelling
+    /// from `start` towards `end`, and negative when it lies to the left.
+    pub fn cross_track_distance(&self, start: &GeoCoord, end: &GeoCoord) -> f64 {
+        let angular_distance = start.haversine_distance(self) / EARTH_MEAN_RADIUS;
+        let bearing_diff = (start.bearing_to(self) - start.bearing_to(end)).to_radians();
+
+        (angular_distance.sin() * bearing_diff.sin()).clamp(-1.0, 1.0).asin() * EARTH_MEAN_RADIUS
+    }
+
+    /// Distance in meters from `start` to the point on the great circle through `start` and
+    /// `end` closest to `self`, on a sphere of `EARTH_MEAN_RADIUS`.
+    ///
+    /// The result is negative when that closest point lies behind `start`.
+    pub fn along_track_distance(&self, start: &GeoCoord, end: &GeoCoord) -> f64 {
+        let angular_distance = start.haversine_distance(self) / EARTH_MEAN_RADIUS;
+        let bearing_diff = (start.bearing_to(self) - start.bearing_to(end)).to_radians();
+        let cross_track = self.cross_track_distance(start, end) / EARTH_MEAN_RADIUS;
+
+        let along_track = (angular_distance.cos() / cross_track.cos()).clamp(-1.0, 1.0).acos();
+
+        if bearing_diff.cos() < 0.0 {
+            -along_track * EARTH_MEAN_RADIUS
+        } else {
+            along_track * EARTH_MEAN_RADIUS
+        }
+    }
+
+    /// Point at `fraction` of the way along the great circle to `other`, given the angular
+    /// distance between the two in radians.
+    fn interpolate(&self, other: &GeoCoord, angular_distance: f64, fraction: f64) -> GeoCoord {
+        let sin_distance = angular_distance.sin();
+
+        if sin_distance.abs() < 1e-12 {
+            if angular_distance < FRAC_PI_2 {
+                return if fraction < 0.5 { *self } else { *other };
+            }
+
+            // Every great circle joins antipodal points, so just keep the initial bearing
+            return self.destination(self.bearing_to(other), fraction * angular_distance * EARTH_MEAN_RADIUS);
+        }
+
+        let a = ((1.0 - fraction) * angular_distance).sin() / sin_distance;
+        let b = (fraction * angular_distance).sin() / sin_distance;
+
+        let (lat_a, lat_b) = (self.lat_rad(), other.lat_rad());
+        let (lon_a, lon_b) = (self.lon_rad(), other.lon_rad());
+
+        let x = a * lat_a.cos() * lon_a.cos() + b * lat_b.cos() * lon_b.cos();
+        let y = a * lat_a.cos() * lon_a.sin() + b * lat_b.cos() * lon_b.sin();
+        let z = a * lat_a.sin() + b * lat_b.sin();
+
+        GeoCoord {
+            lon: wrap_lon(y.atan2(x).to_degrees()),
+            lat: z.atan2(x.hypot(y)).to_degrees().clamp(-90.0, 90.0),
+        }
+    }
+}
+
+pub(crate) fn wrap_lon(lon: f64) -> f64 {
+    if (-180.0..=180.0).contains(&lon) {
+        lon
+    } else {
+        (lon + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+fn normalize_bearing(deg: f64) -> f64 {
+    let normalized = deg.rem_euclid(360.0);
+
+    // rem_euclid may round tiny negative values up to exactly 360
+    if normalized >= 360.0 { 0.0 } else { normalized }
+}
+
+/// Formats the coordinate as `lat,lon`, following the usual GIS convention.
+impl fmt::Display for GeoCoord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.lat, self.lon)
+    }
+}
+
+/// Parses a `lat,lon` pair as produced by `Display`.
+impl FromStr for GeoCoord {
+    type Err = InvalidGeoCoord;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lat, lon) = s.trim().split_once(',').ok_or(InvalidGeoCoord)?;
+
+        let lat = lat.trim_end().parse().map_err(|_| InvalidGeoCoord)?;
+        let lon = lon.trim_start().parse().map_err(|_| InvalidGeoCoord)?;
+
+        GeoCoord::from_degrees(lon, lat)
+    }
+}
+
+/// Unvalidated `GeoCoord` used for deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawGeoCoord {
+    lon: f64,
+    lat: f64,
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<RawGeoCoord> for GeoCoord {
+    type Error = InvalidGeoCoord;
+
+    fn try_from(raw: RawGeoCoord) -> Result<Self, Self::Error> {
+        GeoCoord::from_degrees(raw.lon, raw.lat)
+    }
+}
+
+/// Validates a `(lon, lat)` pair, longitude first like `from_degrees`.
+impl core::convert::TryFrom<(f64, f64)> for GeoCoord {
+    type Error = InvalidGeoCoord;
+
+    fn try_from(pair: (f64, f64)) -> Result<Self, Self::Error> {
+        GeoCoord::from_degrees(pair.0, pair.1)
+    }
+}
+
+/// Converts into a `(lon, lat)` pair, longitude first.
+impl From<GeoCoord> for (f64, f64) {
+    fn from(coord: GeoCoord) -> Self {
+        (coord.lon, coord.lat)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<GeoCoord> for geo_types::Coord<f64> {
+    fn from(coord: GeoCoord) -> Self {
+        geo_types::Coord { x: coord.lon, y: coord.lat }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<GeoCoord> for geo_types::Point<f64> {
+    fn from(coord: GeoCoord) -> Self {
+        geo_types::Point::new(coord.lon, coord.lat)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl core::convert::TryFrom<geo_types::Coord<f64>> for GeoCoord {
+    type Error = InvalidGeoCoord;
+
+    fn try_from(coord: geo_types::Coord<f64>) -> Result<Self, Self::Error> {
+        GeoCoord::from_degrees(coord.x, coord.y)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl core::convert::TryFrom<geo_types::Point<f64>> for GeoCoord {
+    type Error = InvalidGeoCoord;
+
+    fn try_from(point: geo_types::Point<f64>) -> Result<Self, Self::Error> {
+        GeoCoord::from_degrees(point.x(), point.y())
+    }
 }
 
 impl PartialEq<GeoCoord> for GeoCoord {
     fn eq(&self, other: &GeoCoord) -> bool {
-        if self.lon.abs() == 180.0 && other.lon.abs() == 180.0 {
-            true
-        } else if self.lat == other.lat && self.lat.abs() == 90.0 {
-            true
+        if self.lat != other.lat {
+            false
         } else {
-            self.lon == other.lon && self.lat == other.lat
+            self.lon == other.lon || self.is_pole() || (self.is_on_dateline() && other.is_on_dateline())
         }
     }
 }
 
+impl Eq for GeoCoord {}
+
+impl Hash for GeoCoord {
+    /// Hashes equal coordinates alike by ignoring the longitude at the poles and treating -180°
+    /// as 180°.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let lon = if self.is_pole() {
+            0.0
+        } else if self.lon == -180.0 {
+            180.0
+        } else {
+            // Turns -0.0 into 0.0
+            self.lon + 0.0
+        };
+
+        lon.to_bits().hash(state);
+        (self.lat + 0.0).to_bits().hash(state);
+    }
+}
+
 /// A WGS84 coordinate encoded into two 32-bit integers.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompactGeoCoord {
     lon: i32,
     lat: i32,
@@ -71,18 +597,150 @@ impl From<CompactGeoCoord> for GeoCoord {
     }
 }
 
-fn interleave(x: i64, y: i64) -> i64 {
-    let mut morton: i64 = 0;
-    // TODO: optimize
-    for i in 0..32 {
-        morton |= (x & 1i64 << i) << i | (y & 1i64 << i) << (i + 1);
+/// Orders coordinates spatially along the Z-order curve, see `morton_code`, rather than
+/// lexicographically by longitude and latitude.
+impl Ord for CompactGeoCoord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.morton_code().cmp(&other.morton_code())
+    }
+}
+
+impl PartialOrd for CompactGeoCoord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Stably sorts coordinates along the Z-order curve of their `CompactGeoCoord` encoding, so
+/// that nearby coordinates tend to end up next to each other.
+pub fn spatial_sort(coords: &mut [GeoCoord]) {
+    coords.sort_by_cached_key(|coord| CompactGeoCoord::from(*coord).morton_code());
+}
+
+/// Spreads the bits of `v` to the even bit positions of the result.
+pub(crate) fn spread_bits(v: u32) -> u64 {
+    let mut v = v as u64;
+    v = (v | v << 16) & 0x0000_FFFF_0000_FFFF;
+    v = (v | v << 8) & 0x00FF_00FF_00FF_00FF;
+    v = (v | v << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | v << 2) & 0x3333_3333_3333_3333;
+    v = (v | v << 1) & 0x5555_5555_5555_5555;
+    v
+}
+
+/// Gathers the even bits of `v`, the inverse of `spread_bits`.
+fn compact_bits(v: u64) -> u32 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | v >> 1) & 0x3333_3333_3333_3333;
+    v = (v | v >> 2) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | v >> 4) & 0x00FF_00FF_00FF_00FF;
+    v = (v | v >> 8) & 0x0000_FFFF_0000_FFFF;
+    v = (v | v >> 16) & 0x0000_0000_FFFF_FFFF;
+    v as u32
+}
+
+/// Distance along the Hilbert curve filling a square grid of `2^order` cells per side, with
+/// `order` at most 32.
+pub(crate) fn hilbert_index(x: u32, y: u32, order: u32) -> u64 {
+    let (mut x, mut y) = (x as u64, y as u64);
+    let mut index = 0;
+
+    for bit in (0..order).rev() {
+        let s = 1u64 << bit;
+        let rx = (x & s != 0) as u64;
+        let ry = (y & s != 0) as u64;
+
+        index += s * s * ((3 * rx) ^ ry);
+        hilbert_rotate(s, &mut x, &mut y, rx, ry);
+    }
+
+    index
+}
+
+/// Cell at the given distance along the Hilbert curve, the inverse of `hilbert_index`.
+pub(crate) fn hilbert_cell(index: u64, order: u32) -> (u32, u32) {
+    let (mut x, mut y) = (0, 0);
+    let mut t = index;
+
+    for bit in 0..order {
+        let s = 1u64 << bit;
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+
+        hilbert_rotate(s, &mut x, &mut y, rx, ry);
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+    }
+
+    (x as u32, y as u32)
+}
+
+/// Rotates and flips a quadrant of side `s` into the orientation of the curve.
+fn hilbert_rotate(s: u64, x: &mut u64, y: &mut u64, rx: u64, ry: u64) {
+    if ry == 0 {
+        if rx == 1 {
+            *x = (s - 1).wrapping_sub(*x) & (s - 1);
+            *y = (s - 1).wrapping_sub(*y) & (s - 1);
+        }
+
+        core::mem::swap(x, y);
     }
-    morton
+}
+
+fn interleave(x: i64, y: i64) -> i64 {
+    (spread_bits(x as u32) | spread_bits(y as u32) << 1) as i64
 }
 
 impl CompactGeoCoord {
+    /// Z-order curve index of the coordinate.
+    ///
+    /// Both axes are biased by 2^31 to unsigned values before interleaving, so that the curve
+    /// runs continuously from the south-west to the north-east corner instead of jumping at the
+    /// equator and the prime meridian. The sign bit of the result is flipped afterwards, making
+    /// the ordering of the returned `i64` values follow the curve.
     pub fn morton_code(&self) -> i64 {
-        interleave(self.lon as i64, self.lat as i64)
+        let lon = (self.lon as u32 ^ 1 << 31) as i64;
+        let lat = (self.lat as u32 ^ 1 << 31) as i64;
+
+        interleave(lon, lat) ^ i64::MIN
+    }
+
+    /// Decodes a Z-order curve index produced by `morton_code`.
+    pub fn from_morton_code(code: i64) -> CompactGeoCoord {
+        let code = (code ^ i64::MIN) as u64;
+
+        CompactGeoCoord {
+            lon: (compact_bits(code) ^ 1 << 31) as i32,
+            lat: (compact_bits(code >> 1) ^ 1 << 31) as i32,
+        }
+    }
+
+    /// Big-endian encoding of the Z-order curve position, so that comparing the bytes
+    /// lexicographically gives the same order as comparing the coordinates.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        ((self.morton_code() ^ i64::MIN) as u64).to_be_bytes()
+    }
+
+    /// Decodes the bytes produced by `to_bytes`.
+    pub fn from_bytes(bytes: [u8; 8]) -> CompactGeoCoord {
+        CompactGeoCoord::from_morton_code(u64::from_be_bytes(bytes) as i64 ^ i64::MIN)
+    }
+
+    /// Hilbert curve index of the coordinate.
+    ///
+    /// Like `morton_code` both axes are biased to unsigned values first, so the curve covers
+    /// the globe without jumps at the equator and the prime meridian. Unlike the Z-order curve
+    /// consecutive indices are always adjacent, giving better locality for range queries.
+    pub fn hilbert_index(&self) -> u64 {
+        hilbert_index(self.lon as u32 ^ 1 << 31, self.lat as u32 ^ 1 << 31, 32)
+    }
+
+    /// Decodes a Hilbert curve index produced by `hilbert_index`.
+    pub fn from_hilbert_index(index: u64) -> CompactGeoCoord {
+        let (lon, lat) = hilbert_cell(index, 32);
+
+        CompactGeoCoord { lon: (lon ^ 1 << 31) as i32, lat: (lat ^ 1 << 31) as i32 }
     }
 }
 
@@ -95,12 +753,33 @@ impl fmt::Display for InvalidGeoCoord {
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for InvalidGeoCoord {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         None
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum VincentyError {
+    NotConverged,
+}
+
+impl fmt::Display for VincentyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VincentyError::NotConverged => write!(f, "Vincenty's formula failed to converge"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for VincentyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
 /// Tile relative "pixel" coordinate.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct TileCoord {
@@ -114,35 +793,219 @@ impl TileCoord {
     }
 
     pub fn diff_to(&self, to: &TileCoord) -> TileCoord {
-        TileCoord { x: to.x - self.x, y: to.y - self.y }
+        *to - *self
     }
-}
 
-impl From<(i32, i32)> for TileCoord {
-    fn from(pair: (i32, i32)) -> Self {
-        TileCoord { x: pair.0, y: pair.1 }
+    /// Euclidean length of the coordinate as a vector.
+    pub fn magnitude(&self) -> f64 {
+        f64::from(self.x).hypot(f64::from(self.y))
+    }
+
+    /// Euclidean distance to `other` in pixels.
+    pub fn distance_to(&self, other: &TileCoord) -> f64 {
+        (f64::from(other.x) - f64::from(self.x)).hypot(f64::from(other.y) - f64::from(self.y))
+    }
+
+    /// Clamps both axes into the pixel range `0..extent` of a tile.
+    pub fn clamp(&self, extent: i32) -> TileCoord {
+        let max = (extent - 1).max(0);
+
+        TileCoord { x: self.x.clamp(0, max), y: self.y.clamp(0, max) }
+    }
+
+    /// Whether both axes are within the pixel range `0..extent` of a tile.
+    pub fn is_within(&self, extent: i32) -> bool {
+        (0..extent).contains(&self.x) && (0..extent).contains(&self.y)
     }
 }
 
-impl Into<(i32, i32)> for TileCoord {
-    fn into(self) -> (i32, i32) {
-        (self.x, self.y)
+impl Add for TileCoord {
+    type Output = TileCoord;
+
+    fn add(self, other: TileCoord) -> TileCoord {
+        TileCoord { x: self.x + other.x, y: self.y + other.y }
     }
 }
 
-#[cfg(test)]
-mod geo_coord_tests {
-    use super::*;
+impl Sub for TileCoord {
+    type Output = TileCoord;
+
+    fn sub(self, other: TileCoord) -> TileCoord {
+        TileCoord { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<i32> for TileCoord {
+    type Output = TileCoord;
+
+    fn mul(self, factor: i32) -> TileCoord {
+        TileCoord { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl Neg for TileCoord {
+    type Output = TileCoord;
+
+    fn neg(self) -> TileCoord {
+        TileCoord { x: -self.x, y: -self.y }
+    }
+}
+
+impl From<(i32, i32)> for TileCoord {
+    fn from(pair: (i32, i32)) -> Self {
+        TileCoord { x: pair.0, y: pair.1 }
+    }
+}
+
+impl From<TileCoord> for (i32, i32) {
+    fn from(coord: TileCoord) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
+#[cfg(test)]
+mod geo_coord_tests {
+    use super::*;
+    use crate::test_utils::XorShift64;
 
     #[test]
     fn construction() {
         assert_eq!(GeoCoord::from_degrees(0.0, 91.0), Err(InvalidGeoCoord));
         assert_eq!(GeoCoord::from_degrees(181.0, 0.0), Err(InvalidGeoCoord));
 
-        assert_eq!(GeoCoord::from_degrees(0.0, std::f64::NAN), Err(InvalidGeoCoord));
-        assert_eq!(GeoCoord::from_degrees(0.0, std::f64::INFINITY), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_degrees(0.0, f64::NAN), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_degrees(0.0, f64::INFINITY), Err(InvalidGeoCoord));
 
         assert!(GeoCoord::from_degrees(2.2945, 48.858222).is_ok());
+
+        for &(lon, lat) in &[(2.2945, 48.858222), (-180.0, -90.0), (180.0, 90.0), (0.0, 91.0), (f64::NAN, 0.0)] {
+            assert_eq!(GeoCoord::new(lon, lat), GeoCoord::from_degrees(lon, lat));
+        }
+    }
+
+    #[test]
+    fn pole_and_dateline() -> Result<(), InvalidGeoCoord> {
+        assert!(GeoCoord::from_degrees(0.0, 90.0)?.is_pole());
+        assert!(GeoCoord::from_degrees(123.0, -90.0)?.is_pole());
+        assert!(!GeoCoord::from_degrees(0.0, 89.999)?.is_pole());
+
+        assert!(GeoCoord::from_degrees(180.0, 10.0)?.is_on_dateline());
+        assert!(GeoCoord::from_degrees(-180.0, -10.0)?.is_on_dateline());
+        assert!(!GeoCoord::from_degrees(179.999, 0.0)?.is_on_dateline());
+        assert!(!GeoCoord::from_degrees(0.0, 0.0)?.is_on_dateline());
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalized_construction() -> Result<(), InvalidGeoCoord> {
+        assert_eq!(GeoCoord::from_degrees_normalized(190.0, 10.0)?.lon(), -170.0);
+        assert_eq!(GeoCoord::from_degrees_normalized(-190.0, 10.0)?.lon(), 170.0);
+        assert_eq!(GeoCoord::from_degrees_normalized(540.0, 10.0)?.lon(), 180.0);
+        assert_eq!(GeoCoord::from_degrees_normalized(-540.0, 10.0)?.lon(), -180.0);
+        assert_eq!(GeoCoord::from_degrees_normalized(360.0, 10.0)?.lon(), 0.0);
+        assert_eq!(GeoCoord::from_degrees_normalized(-720.5, 10.0)?.lon(), -0.5);
+        assert_eq!(GeoCoord::from_degrees_normalized(45.0, 10.0)?, GeoCoord::from_degrees(45.0, 10.0)?);
+        assert_eq!(GeoCoord::from_degrees_normalized(180.0, 10.0)?.lon(), 180.0);
+
+        assert_eq!(GeoCoord::from_degrees_normalized(190.0, 91.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_degrees_normalized(f64::NAN, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_degrees_normalized(f64::INFINITY, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_degrees_normalized(f64::NEG_INFINITY, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_degrees_normalized(0.0, f64::NAN), Err(InvalidGeoCoord));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clamped() -> Result<(), InvalidGeoCoord> {
+        assert_eq!(GeoCoord::clamped(200.0, 95.0), GeoCoord::from_degrees(180.0, 90.0)?);
+        assert_eq!(GeoCoord::clamped(-200.0, -95.0), GeoCoord::from_degrees(-180.0, -90.0)?);
+        assert_eq!(GeoCoord::clamped(2.2945, 48.858222), GeoCoord::from_degrees(2.2945, 48.858222)?);
+
+        let clamped = GeoCoord::clamped(f64::INFINITY, f64::NEG_INFINITY);
+        assert_eq!((clamped.lon(), clamped.lat()), (180.0, -90.0));
+
+        let clamped = GeoCoord::clamped(f64::NAN, 45.0);
+        assert_eq!((clamped.lon(), clamped.lat()), (0.0, 45.0));
+
+        let clamped = GeoCoord::clamped(200.0, 95.0);
+        assert_eq!((clamped.lon(), clamped.lat()), (180.0, 90.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn radians() -> Result<(), InvalidGeoCoord> {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let coord = GeoCoord::from_radians(PI, -FRAC_PI_2)?;
+        assert_eq!((coord.lon(), coord.lat()), (180.0, -90.0));
+        assert_eq!((coord.lon_rad(), coord.lat_rad()), (PI, -FRAC_PI_2));
+
+        assert_eq!(GeoCoord::from_radians(0.0, 1.6), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_radians(3.2, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_radians(f64::NAN, 0.0), Err(InvalidGeoCoord));
+
+        for &(lon, lat) in &[(2.2945, 48.858222), (-179.9, -89.9), (123.456, 0.001)] {
+            let coord = GeoCoord::from_degrees(lon, lat)?;
+            let round_trip = GeoCoord::from_radians(coord.lon_rad(), coord.lat_rad())?;
+
+            assert!((round_trip.lon() - lon).abs() < 1e-12);
+            assert!((round_trip.lat() - lat).abs() < 1e-12);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn nanodegrees() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.3522, 48.8566)?;
+        assert_eq!(paris.to_nanodegrees(), (2_352_200_000, 48_856_600_000));
+
+        let (lon, lat) = paris.to_nanodegrees();
+        let round_trip = GeoCoord::from_nanodegrees(lon, lat)?;
+        assert!((round_trip.lon() - paris.lon()).abs() <= 1e-9);
+        assert!((round_trip.lat() - paris.lat()).abs() <= 1e-9);
+        assert_eq!(round_trip.to_nanodegrees(), (lon, lat));
+
+        // Flooring would push tiny negative values to -1 and tiny positive ones below a boundary
+        assert_eq!(GeoCoord::from_degrees(-1e-12, 1e-12)?.to_nanodegrees(), (0, 0));
+        assert_eq!(GeoCoord::from_degrees(-0.000_000_001, 0.000_000_001)?.to_nanodegrees(), (-1, 1));
+        assert_eq!(GeoCoord::from_degrees(0.1, -0.1)?.to_nanodegrees(), (100_000_000, -100_000_000));
+
+        assert_eq!(GeoCoord::from_degrees(-180.0, 90.0)?.to_nanodegrees(), (-180_000_000_000, 90_000_000_000));
+
+        let limits = GeoCoord::from_nanodegrees(-180_000_000_000, 90_000_000_000)?;
+        assert_eq!((limits.lon(), limits.lat()), (-180.0, 90.0));
+        assert_eq!(GeoCoord::from_nanodegrees(180_000_000_001, 0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_nanodegrees(0, -90_000_000_001), Err(InvalidGeoCoord));
+
+        assert_eq!(GeoCoord::from_nanodegrees(i64::MAX, 0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_nanodegrees(0, i64::MAX), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_nanodegrees(i64::MIN, i64::MIN), Err(InvalidGeoCoord));
+
+        Ok(())
+    }
+
+    #[test]
+    fn f32_conversion() -> Result<(), InvalidGeoCoord> {
+        assert_eq!(GeoCoord::from_f32(0.0, 91.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_f32(f32::NAN, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_f32(180.0, -90.0)?.to_f32_pair(), (180.0, -90.0));
+
+        for &(lon, lat) in &[(2.2945, 48.858222), (-179.999_999, -89.999_999), (179.123_456_7, 0.000_001), (-73.985_656, 40.748_433)] {
+            let coord = GeoCoord::from_degrees(lon, lat)?;
+            let (lon_f32, lat_f32) = coord.to_f32_pair();
+            let round_trip = GeoCoord::from_f32(lon_f32, lat_f32)?;
+
+            // Relative rounding error of f32 at the largest magnitude of each axis
+            assert!((round_trip.lon() - lon).abs() <= 180.0 * f64::from(f32::EPSILON) / 2.0);
+            assert!((round_trip.lat() - lat).abs() <= 90.0 * f64::from(f32::EPSILON) / 2.0);
+            assert!(coord.haversine_distance(&round_trip) < 1.5);
+        }
+
+        Ok(())
     }
 
     #[test]
@@ -159,6 +1022,375 @@ mod geo_coord_tests {
         let south_pole = GeoCoord::from_degrees(80.0, -90.0)?;
         assert_ne!(north_pole, south_pole);
 
+        let dateline_north = GeoCoord::from_degrees(180.0, 10.0)?;
+        assert_ne!(dateline_a, dateline_north);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hash() -> Result<(), InvalidGeoCoord> {
+        use std::collections::{HashMap, HashSet};
+
+        let mut names = HashMap::new();
+        names.insert(GeoCoord::from_degrees(-180.0, 0.0)?, "dateline");
+        names.insert(GeoCoord::from_degrees(-80.0, 90.0)?, "north pole");
+        names.insert(GeoCoord::from_degrees(-0.0, 0.0)?, "null island");
+
+        assert_eq!(names.get(&GeoCoord::from_degrees(180.0, 0.0)?), Some(&"dateline"));
+        assert_eq!(names.get(&GeoCoord::from_degrees(80.0, 90.0)?), Some(&"north pole"));
+        assert_eq!(names.get(&GeoCoord::from_degrees(0.0, -0.0)?), Some(&"null island"));
+        assert_eq!(names.get(&GeoCoord::from_degrees(180.0, 1.0)?), None);
+        assert_eq!(names.get(&GeoCoord::from_degrees(80.0, -90.0)?), None);
+
+        let coords: HashSet<_> = vec![
+            GeoCoord::from_degrees(180.0, 45.0)?,
+            GeoCoord::from_degrees(-180.0, 45.0)?,
+            GeoCoord::from_degrees(10.0, -90.0)?,
+            GeoCoord::from_degrees(-10.0, -90.0)?,
+            GeoCoord::from_degrees(2.2945, 48.858222)?,
+        ].into_iter().collect();
+        assert_eq!(coords.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_eq() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222)?;
+        let near_paris = GeoCoord::from_degrees(2.2945 + 1e-9, 48.858222 - 1e-9)?;
+        assert_ne!(paris, near_paris);
+        assert!(paris.approx_eq(&near_paris, 1e-8));
+        assert!(!paris.approx_eq(&near_paris, 1e-10));
+        assert!(!paris.approx_eq(&GeoCoord::from_degrees(2.2945, 48.86)?, 1e-8));
+
+        let dateline_a = GeoCoord::from_degrees(-180.0, 10.0)?;
+        let dateline_b = GeoCoord::from_degrees(180.0 - 1e-9, 10.0)?;
+        assert!(dateline_a.approx_eq(&dateline_b, 1e-8));
+        assert!(dateline_b.approx_eq(&dateline_a, 1e-8));
+        assert!(!dateline_a.approx_eq(&GeoCoord::from_degrees(180.0, 11.0)?, 1e-8));
+
+        let north_pole = GeoCoord::from_degrees(-80.0, 90.0)?;
+        let near_north_pole = GeoCoord::from_degrees(80.0, 90.0 - 1e-9)?;
+        assert!(north_pole.approx_eq(&near_north_pole, 1e-8));
+        assert!(!north_pole.approx_eq(&GeoCoord::from_degrees(80.0, -90.0)?, 1e-8));
+
+        Ok(())
+    }
+
+    #[test]
+    fn haversine_distance() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.3522, 48.8566)?;
+        let london = GeoCoord::from_degrees(-0.1278, 51.5074)?;
+        assert!((paris.haversine_distance(&london) - 343_560.0).abs() < 500.0);
+        assert_eq!(paris.haversine_distance(&london), london.haversine_distance(&paris));
+
+        let new_york = GeoCoord::from_degrees(-74.0060, 40.7128)?;
+        let los_angeles = GeoCoord::from_degrees(-118.2437, 34.0522)?;
+        assert!((new_york.haversine_distance(&los_angeles) - 3_935_750.0).abs() < 2_000.0);
+
+        assert_eq!(paris.haversine_distance(&paris), 0.0);
+
+        let near_paris = GeoCoord::from_degrees(2.3522, 48.8566 + 1e-7)?;
+        assert!((paris.haversine_distance(&near_paris) - 0.0111).abs() < 0.001);
+
+        let a = GeoCoord::from_degrees(0.0, 0.0)?;
+        let b = GeoCoord::from_degrees(180.0, 0.0)?;
+        let half_circumference = std::f64::consts::PI * EARTH_MEAN_RADIUS;
+        assert!((a.haversine_distance(&b) - half_circumference).abs() < 1e-6);
+
+        assert!((a.haversine_distance_with_radius(&b, 1.0) - std::f64::consts::PI).abs() < 1e-12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vincenty() -> Result<(), InvalidGeoCoord> {
+        // Flinders Peak to Buninyong, the classic test case from Geoscience Australia
+        let flinders_peak = GeoCoord::from_degrees(144.424_867_888_9, -37.951_033_416_7)?;
+        let buninyong = GeoCoord::from_degrees(143.926_495_527_8, -37.652_821_138_9)?;
+
+        let (distance, forward, back) = flinders_peak.vincenty_inverse(&buninyong).unwrap();
+        assert!((distance - 54_972.271).abs() < 0.001);
+        assert!((forward - 306.868_158_3).abs() < 1e-6);
+        assert!((back - 127.173_630_6).abs() < 1e-6);
+
+        assert_eq!(flinders_peak.vincenty_distance(&buninyong), Ok(distance));
+        assert_eq!(flinders_peak.vincenty_distance(&flinders_peak), Ok(0.0));
+
+        let a = GeoCoord::from_degrees(0.0, 0.0)?;
+        let b = GeoCoord::from_degrees(90.0, 0.0)?;
+        let quarter_equator = std::f64::consts::PI * WGS84_SEMI_MAJOR_AXIS / 2.0;
+        assert!((a.vincenty_distance(&b).unwrap() - quarter_equator).abs() < 1e-3);
+
+        let near_antipode = GeoCoord::from_degrees(179.7, 0.5)?;
+        assert_eq!(a.vincenty_distance(&near_antipode), Err(VincentyError::NotConverged));
+
+        Ok(())
+    }
+
+    #[test]
+    fn bearing() -> Result<(), InvalidGeoCoord> {
+        let origin = GeoCoord::from_degrees(0.0, 0.0)?;
+        assert!((origin.bearing_to(&GeoCoord::from_degrees(10.0, 0.0)?) - 90.0).abs() < 1e-9);
+        assert!((origin.bearing_to(&GeoCoord::from_degrees(-10.0, 0.0)?) - 270.0).abs() < 1e-9);
+        assert!((origin.bearing_to(&GeoCoord::from_degrees(0.0, -10.0)?) - 180.0).abs() < 1e-9);
+        assert_eq!(origin.bearing_to(&GeoCoord::from_degrees(0.0, 10.0)?), 0.0);
+        assert_eq!(origin.bearing_to(&origin), 0.0);
+
+        let paris = GeoCoord::from_degrees(2.3522, 48.8566)?;
+        let london = GeoCoord::from_degrees(-0.1278, 51.5074)?;
+        assert!((paris.bearing_to(&london) - 330.0).abs() < 0.5);
+        assert!((paris.final_bearing_to(&london) - 328.1).abs() < 0.5);
+
+        let north_pole = GeoCoord::from_degrees(45.0, 90.0)?;
+        let south_pole = GeoCoord::from_degrees(45.0, -90.0)?;
+        assert_eq!(north_pole.bearing_to(&paris), 180.0);
+        assert_eq!(south_pole.bearing_to(&paris), 0.0);
+        assert!(paris.bearing_to(&north_pole) < 1e-9);
+        assert!((paris.bearing_to(&south_pole) - 180.0).abs() < 1e-9);
+        assert_eq!(paris.final_bearing_to(&north_pole), 0.0);
+
+        let east_of_dateline = GeoCoord::from_degrees(179.0, 0.0)?;
+        let west_of_dateline = GeoCoord::from_degrees(-179.0, 0.0)?;
+        assert!((east_of_dateline.bearing_to(&west_of_dateline) - 90.0).abs() < 1e-9);
+        assert!((west_of_dateline.bearing_to(&east_of_dateline) - 270.0).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn destination() -> Result<(), InvalidGeoCoord> {
+        let start = GeoCoord::from_degrees(10.0, 45.0)?;
+
+        let there = start.destination(90.0, 1_000_000.0);
+        assert!((start.haversine_distance(&there) - 1_000_000.0).abs() < 1e-3);
+
+        let back = there.destination(normalize_bearing(start.final_bearing_to(&there) + 180.0), 1_000_000.0);
+        assert!(start.haversine_distance(&back) < 1e-3);
+
+        let equator = GeoCoord::from_degrees(179.5, 0.0)?;
+        let across = equator.destination(90.0, 2.0 * std::f64::consts::PI * EARTH_MEAN_RADIUS / 360.0);
+        assert!((across.lon() - -179.5).abs() < 1e-9);
+        assert!(across.lat().abs() < 1e-9);
+
+        let over_the_pole = start.destination(0.0, 10_000_000.0);
+        assert!(GeoCoord::from_degrees(over_the_pole.lon(), over_the_pole.lat()).is_ok());
+        assert!((over_the_pole.lon() - -170.0).abs() < 1e-9);
+
+        assert_eq!(start.destination(f64::NAN, 100.0), start);
+        assert_eq!(start.destination(90.0, f64::NAN), start);
+        assert_eq!(start.destination(f64::INFINITY, 100.0), start);
+        assert_eq!(start.destination(90.0, f64::NEG_INFINITY), start);
+
+        Ok(())
+    }
+
+    #[test]
+    fn midpoint() -> Result<(), InvalidGeoCoord> {
+        let a = GeoCoord::from_degrees(179.0, 0.0)?;
+        let b = GeoCoord::from_degrees(-179.0, 0.0)?;
+        let mid = a.midpoint(&b);
+        assert!((mid.lon().abs() - 180.0).abs() < 1e-9);
+        assert!(mid.lat().abs() < 1e-9);
+
+        // Near the pole the great circle bends far north of the averaged latitude
+        let c = GeoCoord::from_degrees(0.0, 80.0)?;
+        let d = GeoCoord::from_degrees(180.0, 80.0)?;
+        assert!((c.midpoint(&d).lat() - 90.0).abs() < 1e-9);
+
+        let paris = GeoCoord::from_degrees(2.3522, 48.8566)?;
+        let london = GeoCoord::from_degrees(-0.1278, 51.5074)?;
+        let mid = paris.midpoint(&london);
+        assert!((mid.haversine_distance(&paris) - mid.haversine_distance(&london)).abs() < 1e-6);
+        assert!((paris.bearing_to(&mid) - paris.bearing_to(&london)).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cross_and_along_track_distance() -> Result<(), InvalidGeoCoord> {
+        let start = GeoCoord::from_degrees(0.0, 0.0)?;
+        let end = GeoCoord::from_degrees(10.0, 0.0)?;
+        let one_degree = EARTH_MEAN_RADIUS.to_radians();
+
+        // North of an eastbound path is on the left
+        let north = GeoCoord::from_degrees(5.0, 1.0)?;
+        assert!((north.cross_track_distance(&start, &end) + one_degree).abs() < 1e-6);
+        assert!((north.along_track_distance(&start, &end) - 5.0 * one_degree).abs() < 1e-6);
+
+        let south = GeoCoord::from_degrees(5.0, -1.0)?;
+        assert!((south.cross_track_distance(&start, &end) - one_degree).abs() < 1e-6);
+
+        // Behind the start the along-track distance is negative
+        let behind = GeoCoord::from_degrees(-2.0, 0.0)?;
+        assert!(behind.cross_track_distance(&start, &end).abs() < 1e-6);
+        assert!((behind.along_track_distance(&start, &end) + 2.0 * one_degree).abs() < 1e-6);
+
+        let on_path = GeoCoord::from_degrees(3.0, 0.0)?;
+        assert!(on_path.cross_track_distance(&start, &end).abs() < 1e-6);
+        assert!((on_path.along_track_distance(&start, &end) - 3.0 * one_degree).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn great_circle_path() -> Result<(), InvalidGeoCoord> {
+        let a = GeoCoord::from_degrees(-30.0, 60.0)?;
+        let b = GeoCoord::from_degrees(60.0, 60.0)?;
+
+        let path = a.great_circle_path(&b, 4);
+        assert_eq!(path.len(), 5);
+        assert!(path[0].approx_eq(&a, 1e-9));
+        assert!(path[4].approx_eq(&b, 1e-9));
+        assert!(path[2].approx_eq(&a.midpoint(&b), 1e-9));
+
+        // The path bulges towards the pole instead of following the parallel
+        assert!(path[1..4].iter().all(|coord| coord.lat() > 60.0));
+
+        let step = path[0].haversine_distance(&path[1]);
+        assert!(path.windows(2).all(|pair| (pair[0].haversine_distance(&pair[1]) - step).abs() < 1e-6));
+
+        assert_eq!(a.great_circle_path(&b, 0), vec![a]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn slerp() -> Result<(), InvalidGeoCoord> {
+        let a = GeoCoord::from_degrees(170.0, 10.0)?;
+        let b = GeoCoord::from_degrees(-160.0, -20.0)?;
+
+        assert_eq!(a.slerp(&b, 0.0), a);
+        assert_eq!(a.slerp(&b, 1.0), b);
+        assert!(a.slerp(&b, 0.5).approx_eq(&a.midpoint(&b), 1e-9));
+
+        let quarter = a.slerp(&b, 0.25);
+        assert!((a.haversine_distance(&quarter) * 4.0 - a.haversine_distance(&b)).abs() < 1e-6);
+
+        // Antipodal points still yield a point a quarter of the globe away from both
+        let c = GeoCoord::from_degrees(0.0, 0.0)?;
+        let d = c.antipode();
+        let half_way = c.slerp(&d, 0.5);
+        let quarter_globe = EARTH_MEAN_RADIUS * FRAC_PI_2;
+        assert!((c.haversine_distance(&half_way) - quarter_globe).abs() < 1e-6);
+        assert!((d.haversine_distance(&half_way) - quarter_globe).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn centroid() -> Result<(), InvalidGeoCoord> {
+        assert_eq!(GeoCoord::centroid(&[]), None);
+
+        let single = GeoCoord::from_degrees(12.0, 34.0)?;
+        assert!(GeoCoord::centroid(&[single]).unwrap().approx_eq(&single, 1e-9));
+
+        // A naive lon/lat mean of these would land on the prime meridian
+        let coords = [
+            GeoCoord::from_degrees(170.0, 10.0)?,
+            GeoCoord::from_degrees(-170.0, 10.0)?,
+            GeoCoord::from_degrees(175.0, -10.0)?,
+            GeoCoord::from_degrees(-175.0, -10.0)?,
+        ];
+        let centroid = GeoCoord::centroid(&coords).unwrap();
+        assert!((centroid.lon().abs() - 180.0).abs() < 1e-9);
+        assert!(centroid.lat().abs() < 1.0);
+
+        let equator = GeoCoord::from_degrees(0.0, 0.0)?;
+        assert_eq!(GeoCoord::centroid(&[equator, equator.antipode()]), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn antipode() -> Result<(), InvalidGeoCoord> {
+        let antipode = GeoCoord::from_degrees(0.0, 0.0)?.antipode();
+        assert_eq!((antipode.lon(), antipode.lat()), (180.0, 0.0));
+
+        assert_eq!(GeoCoord::from_degrees(90.0, 30.0)?.antipode(), GeoCoord::from_degrees(-90.0, -30.0)?);
+        assert_eq!(GeoCoord::from_degrees(-90.0, -30.0)?.antipode(), GeoCoord::from_degrees(90.0, 30.0)?);
+        assert_eq!(GeoCoord::from_degrees(123.0, 45.0)?.antipode(), GeoCoord::from_degrees(-57.0, -45.0)?);
+
+        assert_eq!(GeoCoord::from_degrees(10.0, 90.0)?.antipode(), GeoCoord::from_degrees(0.0, -90.0)?);
+        assert_eq!(GeoCoord::from_degrees(180.0, 10.0)?.antipode(), GeoCoord::from_degrees(0.0, -10.0)?);
+        assert_eq!(GeoCoord::from_degrees(-180.0, 10.0)?.antipode(), GeoCoord::from_degrees(0.0, -10.0)?);
+
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222)?;
+        assert!(paris.antipode().antipode().approx_eq(&paris, 1e-12));
+        assert!((paris.haversine_distance(&paris.antipode()) - std::f64::consts::PI * EARTH_MEAN_RADIUS).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_and_parse() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222)?;
+        assert_eq!(paris.to_string(), "48.858222,2.2945");
+        assert_eq!(paris.to_string().parse(), Ok(paris));
+
+        let rio = GeoCoord::from_degrees(-43.209611111111116, -22.903499999999998)?;
+        assert_eq!(rio.to_string().parse(), Ok(rio));
+
+        assert_eq!("48.858222, 2.2945".parse(), Ok(paris));
+        assert_eq!("  48.858222 ,2.2945\n".parse(), Ok(paris));
+        assert_eq!("-90,0".parse(), GeoCoord::from_degrees(0.0, -90.0));
+
+        for invalid in &["", "48.858222", "48.858222;2.2945", "48.858222,2.2945,1", "NaN,0", "0,inf", "91,0", "0,181", "a,b"] {
+            assert_eq!(invalid.parse::<GeoCoord>(), Err(InvalidGeoCoord), "{}", invalid);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222)?;
+
+        let json = serde_json::to_string(&paris).unwrap();
+        assert_eq!(json, r#"{"lon":2.2945,"lat":48.858222}"#);
+        assert_eq!(serde_json::from_str::<GeoCoord>(&json).unwrap(), paris);
+
+        assert!(serde_json::from_str::<GeoCoord>(r#"{"lon": 200, "lat": 0}"#).is_err());
+        assert!(serde_json::from_str::<GeoCoord>(r#"{"lon": 0, "lat": -91}"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tuple_conversion() -> Result<(), InvalidGeoCoord> {
+        use core::convert::TryFrom;
+
+        // Longitude first
+        let paris = GeoCoord::try_from((2.2945, 48.858222))?;
+        assert_eq!((paris.lon(), paris.lat()), (2.2945, 48.858222));
+        assert_eq!(<(f64, f64)>::from(paris), (2.2945, 48.858222));
+
+        assert_eq!(GeoCoord::try_from((48.858222, 100.0)), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::try_from((181.0, 0.0)), Err(InvalidGeoCoord));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types() -> Result<(), InvalidGeoCoord> {
+        use core::convert::TryFrom;
+
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222)?;
+
+        let coord: geo_types::Coord<f64> = paris.into();
+        assert_eq!(coord, geo_types::coord! { x: 2.2945, y: 48.858222 });
+        assert_eq!(GeoCoord::try_from(coord)?, paris);
+
+        let point: geo_types::Point<f64> = paris.into();
+        assert_eq!(point, geo_types::Point::new(2.2945, 48.858222));
+        assert_eq!(GeoCoord::try_from(point)?, paris);
+
+        assert_eq!(GeoCoord::try_from(geo_types::Point::new(0.0, 91.0)), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::try_from(geo_types::coord! { x: f64::NAN, y: 0.0 }), Err(InvalidGeoCoord));
+
         Ok(())
     }
 
@@ -168,9 +1400,261 @@ mod geo_coord_tests {
 
         let encoded_coord = CompactGeoCoord::from(raw_coord);
         assert_eq!(encoded_coord, CompactGeoCoord { lon: 27374451, lat: 582901293 });
-        assert_eq!(encoded_coord.morton_code(), 579221254078012839);
+        assert_eq!(encoded_coord.morton_code(), 5190907272505400743);
 
         let decoded_coord = GeoCoord::from(encoded_coord);
         assert_eq!(decoded_coord, GeoCoord { lon: 2.2944999765604734, lat: 48.858221964910626 });
     }
+
+    #[test]
+    fn morton_code_locality() -> Result<(), InvalidGeoCoord> {
+        let code = |lon, lat| GeoCoord::from_degrees(lon, lat).map(|c| CompactGeoCoord::from(c).morton_code());
+
+        // Ordering follows the coordinates across the prime meridian and the equator
+        assert!(code(-0.0001, 10.0)? < code(0.0001, 10.0)?);
+        assert!(code(10.0, -0.0001)? < code(10.0, 0.0001)?);
+        assert!(code(-180.0, -90.0)? < code(0.0, 0.0)?);
+        assert!(code(0.0, 0.0)? < code(179.9, 89.9)?);
+
+        // Neighbours across 0° stay closer to each other than the far ends of the range
+        let west = code(-0.0001, 10.0)?;
+        let east = code(0.0001, 10.0)?;
+        assert!(east - west < code(170.0, 10.0)? - code(-170.0, 10.0)?);
+
+        let south = code(10.0, -0.0001)?;
+        let north = code(10.0, 0.0001)?;
+        assert!(north - south < code(10.0, 80.0)? - code(10.0, -80.0)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compact_coord_ordering() -> Result<(), InvalidGeoCoord> {
+        let mut coords = Vec::new();
+
+        for &(lon, lat) in &[(10.0, 10.0), (-170.0, 80.0), (0.0, 0.0), (179.0, -89.0), (-0.5, 0.5), (45.0, -45.0), (10.0, 10.0)] {
+            coords.push(CompactGeoCoord::from(GeoCoord::from_degrees(lon, lat)?));
+        }
+
+        coords.sort();
+        assert!(coords.windows(2).all(|pair| pair[0].morton_code() <= pair[1].morton_code()));
+
+        // Spatial rather than lexicographic: a smaller longitude may sort after a larger one
+        let south_east = CompactGeoCoord::from(GeoCoord::from_degrees(10.0, -10.0)?);
+        let north_west = CompactGeoCoord::from(GeoCoord::from_degrees(-10.0, 10.0)?);
+        assert!(south_east < north_west);
+
+        Ok(())
+    }
+
+    #[test]
+    fn spatial_sort() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.3522, 48.8566)?;
+        let versailles = GeoCoord::from_degrees(2.1301, 48.8049)?;
+        let sydney = GeoCoord::from_degrees(151.2093, -33.8688)?;
+        let newcastle = GeoCoord::from_degrees(151.7817, -32.9283)?;
+        let new_york = GeoCoord::from_degrees(-74.006, 40.7128)?;
+        let newark = GeoCoord::from_degrees(-74.1724, 40.7357)?;
+
+        let mut coords = vec![paris, sydney, new_york, versailles, newcastle, newark];
+        super::spatial_sort(&mut coords);
+
+        let position = |coord: &GeoCoord| coords.iter().position(|c| c == coord).unwrap() as i32;
+        assert_eq!((position(&paris) - position(&versailles)).abs(), 1);
+        assert_eq!((position(&sydney) - position(&newcastle)).abs(), 1);
+        assert_eq!((position(&new_york) - position(&newark)).abs(), 1);
+
+        // Coordinates with the same encoding keep their order
+        let a = GeoCoord::from_degrees(-10.0, -20.0)?;
+        let b = GeoCoord::from_degrees(-10.0 + 1e-12, -20.0)?;
+        let mut coords = vec![b, paris, a];
+        super::spatial_sort(&mut coords);
+        assert_eq!(coords, vec![b, a, paris]);
+        assert_ne!(coords[0].lon(), coords[1].lon());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bytes() -> Result<(), InvalidGeoCoord> {
+        let paris = CompactGeoCoord::from(GeoCoord::from_degrees(2.2945, 48.858222)?);
+        assert_eq!(CompactGeoCoord::from_bytes(paris.to_bytes()), paris);
+
+        // The south-west corner is all zeros, the north-east corner all ones
+        let south_west = CompactGeoCoord { lon: i32::MIN, lat: i32::MIN };
+        let north_east = CompactGeoCoord { lon: i32::MAX, lat: i32::MAX };
+        assert_eq!(south_west.to_bytes(), [0; 8]);
+        assert_eq!(north_east.to_bytes(), [0xFF; 8]);
+
+        // The latitude takes the most significant bit
+        let west_equator = CompactGeoCoord { lon: i32::MIN, lat: 0 };
+        assert_eq!(west_equator.to_bytes(), [0x80, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut coords = Vec::new();
+
+        for &(lon, lat) in &[(10.0, 10.0), (-170.0, 80.0), (0.0, 0.0), (179.0, -89.0), (-0.5, 0.5), (45.0, -45.0)] {
+            coords.push(CompactGeoCoord::from(GeoCoord::from_degrees(lon, lat)?));
+        }
+
+        let mut by_bytes = coords.clone();
+        by_bytes.sort_by_key(CompactGeoCoord::to_bytes);
+        coords.sort();
+        assert_eq!(by_bytes, coords);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hilbert_index_round_trip() {
+        let paris = CompactGeoCoord::from(GeoCoord::from_degrees(2.2945, 48.858222).unwrap());
+        assert_eq!(CompactGeoCoord::from_hilbert_index(paris.hilbert_index()), paris);
+
+        let mut rng = XorShift64::new(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..10_000 {
+            let index = rng.next_u64();
+            assert_eq!(CompactGeoCoord::from_hilbert_index(index).hilbert_index(), index);
+        }
+
+        for &(lon, lat) in &[(0, 0), (-1, -1), (i32::MIN, i32::MAX), (i32::MAX, i32::MIN)] {
+            let coord = CompactGeoCoord { lon, lat };
+            assert_eq!(CompactGeoCoord::from_hilbert_index(coord.hilbert_index()), coord);
+        }
+
+        // Consecutive indices are neighbouring cells
+        for index in &[0, 1 << 40, u64::MAX / 3, u64::MAX - 1] {
+            let a = CompactGeoCoord::from_hilbert_index(*index);
+            let b = CompactGeoCoord::from_hilbert_index(index + 1);
+            assert_eq!((a.lon as i64 - b.lon as i64).abs() + (a.lat as i64 - b.lat as i64).abs(), 1);
+        }
+    }
+
+    #[test]
+    fn hilbert_locality() {
+        let mut grid = Vec::new();
+
+        for i in 0..64 {
+            for j in 0..64 {
+                grid.push(GeoCoord::from_degrees(-20.0 + i as f64 * 0.625, -20.0 + j as f64 * 0.625).unwrap());
+            }
+        }
+
+        let mean_step = |coords: &[GeoCoord]| {
+            coords.windows(2).map(|pair| pair[0].haversine_distance(&pair[1])).sum::<f64>() / (coords.len() - 1) as f64
+        };
+
+        let mut by_morton = grid.clone();
+        by_morton.sort_by_key(|coord| CompactGeoCoord::from(*coord).morton_code());
+
+        let mut by_hilbert = grid;
+        by_hilbert.sort_by_key(|coord| CompactGeoCoord::from(*coord).hilbert_index());
+
+        assert!(mean_step(&by_hilbert) < mean_step(&by_morton));
+    }
+
+    #[test]
+    fn morton_code_round_trip() {
+        let paris = CompactGeoCoord::from(GeoCoord::from_degrees(2.2945, 48.858222).unwrap());
+        assert_eq!(CompactGeoCoord::from_morton_code(paris.morton_code()), paris);
+
+        let mut rng = XorShift64::new(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..10_000 {
+            let coord = CompactGeoCoord { lon: rng.next_u64() as i32, lat: rng.next_u64() as i32 };
+            assert_eq!(CompactGeoCoord::from_morton_code(coord.morton_code()), coord);
+        }
+
+        for &(lon, lat) in &[(0, 0), (-1, -1), (i32::MIN, i32::MAX), (i32::MAX, i32::MIN)] {
+            let coord = CompactGeoCoord { lon, lat };
+            assert_eq!(CompactGeoCoord::from_morton_code(coord.morton_code()), coord);
+        }
+    }
+
+    #[test]
+    fn interleave_matches_naive() {
+        fn naive_interleave(x: i64, y: i64) -> i64 {
+            let mut morton: i64 = 0;
+            for i in 0..32 {
+                morton |= (x & 1i64 << i) << i | (y & 1i64 << i) << (i + 1);
+            }
+            morton
+        }
+
+        let mut rng = XorShift64::new(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..10_000 {
+            let (x, y) = (rng.next_u64() as i32 as i64, rng.next_u64() as i32 as i64);
+            assert_eq!(interleave(x, y), naive_interleave(x, y));
+        }
+
+        for &(x, y) in &[(0, 0), (-1, -1), (i32::MIN as i64, i32::MAX as i64), (i32::MAX as i64, i32::MIN as i64)] {
+            assert_eq!(interleave(x, y), naive_interleave(x, y));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tile_coord_tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let a = TileCoord::new(10, 20);
+        let b = TileCoord::new(3, 25);
+
+        assert_eq!(a + b, TileCoord::new(13, 45));
+        assert_eq!(a - b, TileCoord::new(7, -5));
+        assert_eq!(b - a, TileCoord::new(-7, 5));
+        assert_eq!(a * 3, TileCoord::new(30, 60));
+        assert_eq!(b * -2, TileCoord::new(-6, -50));
+        assert_eq!(-a, TileCoord::new(-10, -20));
+        assert_eq!(-(a - b), b - a);
+    }
+
+    #[test]
+    fn magnitude_and_distance() {
+        assert_eq!(TileCoord::new(3, 4).magnitude(), 5.0);
+        assert_eq!(TileCoord::new(-3, -4).magnitude(), 5.0);
+        assert_eq!(TileCoord::new(0, 0).magnitude(), 0.0);
+
+        let a = TileCoord::new(10, 20);
+        let b = TileCoord::new(13, 16);
+        assert_eq!(a.distance_to(&b), 5.0);
+        assert_eq!(b.distance_to(&a), 5.0);
+        assert_eq!(a.distance_to(&a), 0.0);
+
+        let far = TileCoord::new(i32::MIN, 0).distance_to(&TileCoord::new(i32::MAX, 0));
+        assert_eq!(far, u32::MAX as f64);
+    }
+
+    #[test]
+    fn clamp() {
+        assert_eq!(TileCoord::new(10, 20).clamp(256), TileCoord::new(10, 20));
+        assert_eq!(TileCoord::new(-5, 20).clamp(256), TileCoord::new(0, 20));
+        assert_eq!(TileCoord::new(10, -1).clamp(256), TileCoord::new(10, 0));
+        assert_eq!(TileCoord::new(256, 300).clamp(256), TileCoord::new(255, 255));
+        assert_eq!(TileCoord::new(-10, 1000).clamp(4096), TileCoord::new(0, 1000));
+    }
+
+    #[test]
+    fn is_within() {
+        assert!(TileCoord::new(0, 0).is_within(256));
+        assert!(TileCoord::new(255, 255).is_within(256));
+        assert!(!TileCoord::new(-1, 10).is_within(256));
+        assert!(!TileCoord::new(10, -1).is_within(256));
+        assert!(!TileCoord::new(256, 10).is_within(256));
+        assert!(!TileCoord::new(10, 300).is_within(256));
+
+        assert!(TileCoord::new(300, 10).clamp(256).is_within(256));
+    }
+
+    #[test]
+    fn diff_to() {
+        let a = TileCoord::new(10, 20);
+        let b = TileCoord::new(3, 25);
+
+        assert_eq!(a.diff_to(&b), TileCoord::new(-7, 5));
+        assert_eq!(a + a.diff_to(&b), b);
+        assert_eq!(a.diff_to(&a), TileCoord::new(0, 0));
+    }
 }
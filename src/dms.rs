@@ -0,0 +1,313 @@
+use crate::coord::GeoCoord;
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+impl GeoCoord {
+    /// Degrees-minutes-seconds notation with one decimal of seconds, e.g. `48°51'29.6"N 2°17'40.2"E`.
+    pub fn to_dms(&self) -> String {
+        self.to_dms_precision(1)
+    }
+
+    /// Degrees-minutes-seconds notation, latitude first, with the given number of decimals of
+    /// seconds, at most 9.
+    pub fn to_dms_precision(&self, decimals: usize) -> String {
+        let decimals = decimals.min(9);
+
+        format!("{} {}", format_dms(self.lat(), decimals, ('N', 'S')), format_dms(self.lon(), decimals, ('E', 'W')))
+    }
+
+    /// Parses a latitude/longitude pair in degrees-minutes-seconds notation.
+    ///
+    /// Minutes and seconds are optional and the last given part may be fractional. Hemispheres
+    /// can be given with an `N`/`S`/`E`/`W` prefix or suffix or with a signed degree; without
+    /// hemisphere letters latitude is expected first. The two parts can be separated by a comma
+    /// or whitespace, e.g. `48°51'29.6"N, 2°17'40.2"E`, `-22°54.21' -43°12.577'` or `N 48 51 29.6 E 2 17 40.2`.
+    pub fn from_dms(s: &str) -> Result<GeoCoord, ParseDmsError> {
+        let mut parts = Vec::new();
+        let mut current = DmsPart::default();
+
+        let mut chars = s.char_indices().peekable();
+
+        // Whether the last token was a hemisphere suffix, which may be followed by a comma
+        let mut after_suffix = false;
+
+        while let Some((i, c)) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            let was_after_suffix = after_suffix;
+            after_suffix = false;
+
+            match c {
+                ',' => {
+                    if !current.is_empty() {
+                        parts.push(core::mem::take(&mut current));
+                    } else if !was_after_suffix {
+                        return Err(ParseDmsError);
+                    }
+                }
+                'N' | 'S' | 'E' | 'W' => {
+                    match (current.hemisphere, current.next_slot) {
+                        // Suffix of the current part
+                        (None, slot) if slot > 0 => {
+                            current.hemisphere = Some(c);
+                            parts.push(core::mem::take(&mut current));
+                            after_suffix = true;
+                        }
+                        // Prefix of the current part
+                        (None, _) => current.hemisphere = Some(c),
+                        // Prefix of the next part
+                        (Some(_), slot) if slot > 0 => {
+                            parts.push(core::mem::take(&mut current));
+                            current.hemisphere = Some(c);
+                        }
+                        (Some(_), _) => return Err(ParseDmsError),
+                    }
+                }
+                '0'..='9' | '.' | '+' | '-' => {
+                    let mut end = i + c.len_utf8();
+
+                    while let Some(&(j, c)) = chars.peek() {
+                        if c.is_ascii_digit() || c == '.' {
+                            end = j + c.len_utf8();
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+
+                    let value: f64 = s[i..end].parse().map_err(|_| ParseDmsError)?;
+                    let signed = c == '+' || c == '-';
+
+                    while chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+                        chars.next();
+                    }
+
+                    let marked_slot = match chars.peek().map(|&(_, c)| c) {
+                        Some('°') => Some(0),
+                        Some('\'') | Some('′') => Some(1),
+                        Some('"') | Some('″') => Some(2),
+                        _ => None,
+                    };
+
+                    let slot = match marked_slot {
+                        Some(slot) => {
+                            chars.next();
+
+                            // Two apostrophes are a common stand-in for the seconds symbol
+                            if slot == 1 && chars.peek().map(|&(_, c)| c) == Some('\'') {
+                                chars.next();
+                                2
+                            } else {
+                                slot
+                            }
+                        }
+                        None => current.next_slot,
+                    };
+
+                    // A part not following the previous one starts the next coordinate component
+                    if current.next_slot > 0 && slot < current.next_slot {
+                        parts.push(core::mem::take(&mut current));
+                    }
+
+                    if slot > 2 || (signed && slot != 0) {
+                        return Err(ParseDmsError);
+                    }
+
+                    if signed {
+                        current.negative = value.is_sign_negative();
+                    }
+
+                    current.values[slot] = Some(value.abs());
+                    current.next_slot = slot + 1;
+                }
+                _ => return Err(ParseDmsError),
+            }
+        }
+
+        if !current.is_empty() {
+            parts.push(current);
+        }
+
+        if parts.len() != 2 {
+            return Err(ParseDmsError);
+        }
+
+        let is_lon = |part: &DmsPart| part.hemisphere == Some('E') || part.hemisphere == Some('W');
+        let is_lat = |part: &DmsPart| part.hemisphere == Some('N') || part.hemisphere == Some('S');
+
+        let (lat, lon) = if is_lon(&parts[0]) || is_lat(&parts[1]) {
+            (&parts[1], &parts[0])
+        } else {
+            (&parts[0], &parts[1])
+        };
+
+        if is_lon(lat) || is_lat(lon) {
+            return Err(ParseDmsError);
+        }
+
+        GeoCoord::from_degrees(lon.degrees()?, lat.degrees()?).map_err(|_| ParseDmsError)
+    }
+}
+
+#[derive(Default)]
+struct DmsPart {
+    negative: bool,
+    hemisphere: Option<char>,
+    values: [Option<f64>; 3],
+    next_slot: usize,
+}
+
+impl DmsPart {
+    fn is_empty(&self) -> bool {
+        self.next_slot == 0 && self.hemisphere.is_none()
+    }
+
+    fn degrees(&self) -> Result<f64, ParseDmsError> {
+        let degrees = self.values[0].ok_or(ParseDmsError)?;
+        let minutes = self.values[1];
+        let seconds = self.values[2];
+
+        if seconds.is_some() && minutes.is_none() {
+            return Err(ParseDmsError);
+        }
+
+        // Only the last given part may have a fractional value
+        if (minutes.is_some() && degrees.fract() != 0.0) || (seconds.is_some() && minutes.unwrap().fract() != 0.0) {
+            return Err(ParseDmsError);
+        }
+
+        let minutes = minutes.unwrap_or(0.0);
+        let seconds = seconds.unwrap_or(0.0);
+
+        if minutes >= 60.0 || seconds >= 60.0 {
+            return Err(ParseDmsError);
+        }
+
+        let value = degrees + minutes / 60.0 + seconds / 3600.0;
+
+        match (self.negative, self.hemisphere) {
+            (true, Some(_)) => Err(ParseDmsError),
+            (true, None) | (false, Some('S')) | (false, Some('W')) => Ok(-value),
+            _ => Ok(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseDmsError;
+
+impl fmt::Display for ParseDmsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid degrees-minutes-seconds coordinate given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ParseDmsError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+fn format_dms(value: f64, decimals: usize, hemispheres: (char, char)) -> String {
+    // Rounding in whole units of the last printed digit carries correctly into minutes and
+    // degrees, so 59.96" never shows up as 60.0"
+    let scale = 10u64.pow(decimals as u32);
+    let units = (value.abs() * 3600.0 * scale as f64).round() as u64;
+
+    let degrees = units / (3600 * scale);
+    let minutes = units / (60 * scale) % 60;
+    let seconds = (units % (60 * scale)) as f64 / scale as f64;
+
+    let hemisphere = if value < 0.0 && units != 0 { hemispheres.1 } else { hemispheres.0 };
+
+    format!("{}°{}'{:.*}\"{}", degrees, minutes, decimals, seconds, hemisphere)
+}
+
+#[cfg(test)]
+mod dms_tests {
+    use super::*;
+    use crate::coord::InvalidGeoCoord;
+
+    #[test]
+    fn formatting() -> Result<(), InvalidGeoCoord> {
+        let eiffel_tower = GeoCoord::from_degrees(2.2945, 48.858222)?;
+        assert_eq!(eiffel_tower.to_dms(), "48°51'29.6\"N 2°17'40.2\"E");
+        assert_eq!(eiffel_tower.to_dms_precision(0), "48°51'30\"N 2°17'40\"E");
+        assert_eq!(eiffel_tower.to_dms_precision(3), "48°51'29.599\"N 2°17'40.200\"E");
+        assert_eq!(eiffel_tower.to_dms_precision(20), eiffel_tower.to_dms_precision(9));
+
+        let rio = GeoCoord::from_degrees(-43.2096, -22.9035)?;
+        assert_eq!(rio.to_dms(), "22°54'12.6\"S 43°12'34.6\"W");
+
+        assert_eq!(GeoCoord::from_degrees(0.0, 0.0)?.to_dms(), "0°0'0.0\"N 0°0'0.0\"E");
+        assert_eq!(GeoCoord::from_degrees(-0.00000001, -0.00000001)?.to_dms(), "0°0'0.0\"N 0°0'0.0\"E");
+
+        assert_eq!(GeoCoord::from_degrees(0.0, 90.0)?.to_dms(), "90°0'0.0\"N 0°0'0.0\"E");
+        assert_eq!(GeoCoord::from_degrees(0.0, -90.0)?.to_dms(), "90°0'0.0\"S 0°0'0.0\"E");
+        assert_eq!(GeoCoord::from_degrees(180.0, 0.0)?.to_dms(), "0°0'0.0\"N 180°0'0.0\"E");
+        assert_eq!(GeoCoord::from_degrees(-180.0, 0.0)?.to_dms(), "0°0'0.0\"N 180°0'0.0\"W");
+
+        // Seconds rounding up carries into minutes and degrees
+        assert_eq!(GeoCoord::from_degrees(0.0, 10.99999)?.to_dms(), "11°0'0.0\"N 0°0'0.0\"E");
+
+        Ok(())
+    }
+
+    #[test]
+    fn parsing() -> Result<(), ParseDmsError> {
+        let eiffel_tower = GeoCoord::from_degrees(2.2945, 48.858222).unwrap();
+        let approx_eq = |a: GeoCoord, b: GeoCoord| (a.lon() - b.lon()).abs() < 1e-4 && (a.lat() - b.lat()).abs() < 1e-4;
+
+        assert!(approx_eq(GeoCoord::from_dms("48°51'29.6\"N, 2°17'40.2\"E")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("48°51'29.6\"N 2°17'40.2\"E")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("48° 51' 29.6\" N, 2° 17' 40.2\" E")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("2°17'40.2\"E 48°51'29.6\"N")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("N 48°51′29.6″ E 2°17′40.2″")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("48°51'29.6''N 2°17'40.2''E")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("N 48 51 29.6 E 2 17 40.2")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("48°51.4933'N 2°17.67'E")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms("48.858222°N 2.2945°E")?, eiffel_tower));
+        assert!(approx_eq(GeoCoord::from_dms(&eiffel_tower.to_dms())?, eiffel_tower));
+
+        let rio = GeoCoord::from_degrees(-43.2096, -22.9035).unwrap();
+        assert!(approx_eq(GeoCoord::from_dms("22°54'12.6\"S 43°12'34.6\"W")?, rio));
+        assert!(approx_eq(GeoCoord::from_dms("-22°54'12.6\" -43°12'34.6\"")?, rio));
+        assert!(approx_eq(GeoCoord::from_dms("-22°54.21', -43°12.577'")?, rio));
+        assert!(approx_eq(GeoCoord::from_dms("W 43°12'34.6\", S 22°54'12.6\"")?, rio));
+
+        assert!(approx_eq(GeoCoord::from_dms("0°30'S 0°30'W")?, GeoCoord::from_degrees(-0.5, -0.5).unwrap()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parsing_errors() {
+        for invalid in &[
+            "",
+            "48°51'29.6\"N",
+            "48°51'29.6\"N 2°17'40.2\"E 3°N",
+            "48°51'29.6\"N 2°17'40.2\"N",
+            "91°0'0\"N 0°0'0\"E",
+            "48°61'0\"N 2°0'0\"E",
+            "48°51'60\"N 2°0'0\"E",
+            "48.5°51'N 2°E",
+            "-48°51'N 2°E",
+            "48°-51'N 2°E",
+            "48°51'29.6\"X 2°17'40.2\"E",
+            "48°51'29.6\"N,, 2°17'40.2\"E",
+            "abc",
+        ] {
+            assert_eq!(GeoCoord::from_dms(invalid), Err(ParseDmsError), "{}", invalid);
+        }
+    }
+}
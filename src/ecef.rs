@@ -0,0 +1,108 @@
+use crate::coord::{GeoCoord, InvalidGeoCoord, WGS84_FLATTENING, WGS84_SEMI_MAJOR_AXIS};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+
+impl GeoCoord {
+    /// Earth-centered, earth-fixed x/y/z meters of the coordinate at `height_m` above the WGS 84
+    /// ellipsoid.
+    pub fn to_ecef(&self, height_m: f64) -> (f64, f64, f64) {
+        let a = WGS84_SEMI_MAJOR_AXIS;
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+
+        let (sin_lat, cos_lat) = self.lat_rad().sin_cos();
+        let (sin_lon, cos_lon) = self.lon_rad().sin_cos();
+
+        // Prime vertical radius of curvature
+        let n = a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+        (
+            (n + height_m) * cos_lat * cos_lon,
+            (n + height_m) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + height_m) * sin_lat,
+        )
+    }
+
+    /// Coordinate and height above the WGS 84 ellipsoid of earth-centered, earth-fixed x/y/z
+    /// meters, using Bowring's method which is accurate to well below a millimeter for points
+    /// near the surface.
+    ///
+    /// Fails if any value is NaN or infinite.
+    pub fn from_ecef(x: f64, y: f64, z: f64) -> Result<(GeoCoord, f64), InvalidGeoCoord> {
+        let a = WGS84_SEMI_MAJOR_AXIS;
+        let b = a * (1.0 - WGS84_FLATTENING);
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+        let ep2 = (a * a - b * b) / (b * b);
+
+        let p = x.hypot(y);
+        let (sin_theta, cos_theta) = (z * a).atan2(p * b).sin_cos();
+
+        let lat = (z + ep2 * b * sin_theta.powi(3)).atan2(p - e2 * a * cos_theta.powi(3));
+        let lon = y.atan2(x);
+
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let height = p * cos_lat + z * sin_lat - a * (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+        if !height.is_finite() {
+            return Err(InvalidGeoCoord);
+        }
+
+        let coord = GeoCoord::from_degrees(
+            lon.to_degrees().clamp(-180.0, 180.0),
+            lat.to_degrees().clamp(-90.0, 90.0),
+        )?;
+
+        Ok((coord, height))
+    }
+}
+
+#[cfg(test)]
+mod ecef_tests {
+    use super::*;
+
+    #[test]
+    fn to_ecef() {
+        let (x, y, z) = GeoCoord::from_degrees(0.0, 0.0).unwrap().to_ecef(0.0);
+        assert_eq!((x, y, z), (WGS84_SEMI_MAJOR_AXIS, 0.0, 0.0));
+
+        let (x, y, z) = GeoCoord::from_degrees(90.0, 0.0).unwrap().to_ecef(100.0);
+        assert!(x.abs() < 1e-6);
+        assert!((y - WGS84_SEMI_MAJOR_AXIS - 100.0).abs() < 1e-6);
+        assert_eq!(z, 0.0);
+
+        let semi_minor_axis = WGS84_SEMI_MAJOR_AXIS * (1.0 - WGS84_FLATTENING);
+        let (x, y, z) = GeoCoord::from_degrees(0.0, 90.0).unwrap().to_ecef(0.0);
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+        assert!((z - semi_minor_axis).abs() < 1e-6);
+
+        // Example of the EPSG guidance note 7-2
+        let lon = 2.0 + 7.0 / 60.0 + 46.38 / 3600.0;
+        let lat = 53.0 + 48.0 / 60.0 + 33.82 / 3600.0;
+        let (x, y, z) = GeoCoord::from_degrees(lon, lat).unwrap().to_ecef(73.0);
+        assert!((x - 3_771_793.968).abs() < 1e-3);
+        assert!((y - 140_253.342).abs() < 1e-3);
+        assert!((z - 5_124_304.349).abs() < 1e-3);
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), InvalidGeoCoord> {
+        for &(lon, lat) in &[(0.0, 0.0), (-120.5, 0.0), (2.2945, 48.858222), (-45.0, -60.0), (150.0, 85.0), (10.0, -89.9), (180.0, 30.0)] {
+            for &height in &[0.0, 1_000.0, -100.0, 10_000.0] {
+                let coord = GeoCoord::from_degrees(lon, lat)?;
+                let (x, y, z) = coord.to_ecef(height);
+                let (round_trip, round_trip_height) = GeoCoord::from_ecef(x, y, z)?;
+
+                assert!(round_trip.approx_eq(&coord, 1e-9), "{:?} at {} m", coord, height);
+                assert!((round_trip_height - height).abs() < 1e-3, "{:?} at {} m", coord, height);
+            }
+        }
+
+        let (north_pole, height) = GeoCoord::from_ecef(0.0, 0.0, 6_400_000.0)?;
+        assert_eq!(north_pole, GeoCoord::from_degrees(0.0, 90.0)?);
+        assert!((height - (6_400_000.0 - WGS84_SEMI_MAJOR_AXIS * (1.0 - WGS84_FLATTENING))).abs() < 1e-6);
+
+        assert_eq!(GeoCoord::from_ecef(f64::NAN, 0.0, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_ecef(0.0, 0.0, f64::INFINITY), Err(InvalidGeoCoord));
+
+        Ok(())
+    }
+}
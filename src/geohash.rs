@@ -0,0 +1,162 @@
+use crate::coord::GeoCoord;
+use crate::rect::GeoRect;
+
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Longest geohash produced, beyond which further characters exceed the resolution of `f64`.
+pub const MAX_GEOHASH_PRECISION: usize = 22;
+
+impl GeoCoord {
+    /// Geohash of the coordinate with `precision` characters, capped at `MAX_GEOHASH_PRECISION`.
+    pub fn to_geohash(&self, precision: usize) -> String {
+        let precision = precision.min(MAX_GEOHASH_PRECISION);
+
+        let mut lon_range = (-180.0, 180.0);
+        let mut lat_range = (-90.0, 90.0);
+
+        let mut geohash = String::with_capacity(precision);
+        let mut even_bit = true;
+
+        while geohash.len() < precision {
+            let mut index = 0;
+
+            for _ in 0..5 {
+                let (value, range) = if even_bit {
+                    (self.lon(), &mut lon_range)
+                } else {
+                    (self.lat(), &mut lat_range)
+                };
+
+                let mid = (range.0 + range.1) / 2.0;
+
+                if value >= mid {
+                    index = index << 1 | 1;
+                    range.0 = mid;
+                } else {
+                    index <<= 1;
+                    range.1 = mid;
+                }
+
+                even_bit = !even_bit;
+            }
+
+            geohash.push(BASE32[index] as char);
+        }
+
+        geohash
+    }
+
+    /// Center of the geohash cell.
+    pub fn from_geohash(geohash: &str) -> Result<GeoCoord, InvalidGeohash> {
+        GeoRect::from_geohash(geohash).map(|bbox| bbox.center())
+    }
+}
+
+impl GeoRect {
+    /// Bounding box of the geohash cell.
+    pub fn from_geohash(geohash: &str) -> Result<GeoRect, InvalidGeohash> {
+        let mut lon_range = (-180.0, 180.0);
+        let mut lat_range = (-90.0, 90.0);
+
+        let mut even_bit = true;
+
+        for c in geohash.bytes() {
+            let index = BASE32.iter().position(|&b| b == c).ok_or(InvalidGeohash)?;
+
+            for bit in (0..5).rev() {
+                let range = if even_bit { &mut lon_range } else { &mut lat_range };
+                let mid = (range.0 + range.1) / 2.0;
+
+                if index >> bit & 1 == 1 {
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+
+                even_bit = !even_bit;
+            }
+        }
+
+        Ok(GeoRect::new(
+            GeoCoord::from_degrees(lon_range.0, lat_range.1).unwrap(),
+            GeoCoord::from_degrees(lon_range.1, lat_range.0).unwrap(),
+        ).unwrap())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidGeohash;
+
+impl fmt::Display for InvalidGeohash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid geohash given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidGeohash {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod geohash_tests {
+    use super::*;
+    use crate::coord::InvalidGeoCoord;
+
+    #[test]
+    fn encoding() -> Result<(), InvalidGeoCoord> {
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222)?;
+        assert_eq!(paris.to_geohash(7), "u09tunq");
+        assert_eq!(paris.to_geohash(12), "u09tunqgc8xn");
+        assert_eq!(paris.to_geohash(0), "");
+
+        assert_eq!(GeoCoord::from_degrees(-5.6, 42.6)?.to_geohash(5), "ezs42");
+        assert_eq!(GeoCoord::from_degrees(10.40744, 57.64911)?.to_geohash(11), "u4pruydqqvj");
+
+        assert_eq!(GeoCoord::from_degrees(180.0, 90.0)?.to_geohash(5), "zzzzz");
+        assert_eq!(GeoCoord::from_degrees(-180.0, -90.0)?.to_geohash(5), "00000");
+
+        let long_geohash = paris.to_geohash(100);
+        assert_eq!(long_geohash.len(), 22);
+        assert!(long_geohash.starts_with("u09tunqgc8xn"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decoding() -> Result<(), InvalidGeohash> {
+        let center = GeoCoord::from_geohash("ezs42")?;
+        assert!((center.lon() - -5.603).abs() < 0.001);
+        assert!((center.lat() - 42.605).abs() < 0.001);
+
+        let bbox = GeoRect::from_geohash("ezs42")?;
+        assert_eq!(bbox.top_left(), GeoCoord::from_degrees(-5.625, 42.626953125).unwrap());
+        assert_eq!(bbox.bottom_right(), GeoCoord::from_degrees(-5.5810546875, 42.5830078125).unwrap());
+        assert_eq!(bbox.center(), center);
+
+        assert_eq!(GeoCoord::from_geohash(""), Ok(GeoCoord::from_degrees(0.0, 0.0).unwrap()));
+
+        for invalid in &["a", "u09i", "ul", "u0o", "u09 t", "U09"] {
+            assert_eq!(GeoCoord::from_geohash(invalid), Err(InvalidGeohash));
+            assert_eq!(GeoRect::from_geohash(invalid), Err(InvalidGeohash));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), InvalidGeohash> {
+        for geohash in &["u09tunq", "ezs42", "u4pruydqqvj", "zzzzz", "00000", "s"] {
+            assert_eq!(&GeoCoord::from_geohash(geohash)?.to_geohash(geohash.len()), geohash);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//! GeoJSON output of the geometry types, written by hand to avoid pulling in a JSON library.
+
+use crate::coord::GeoCoord;
+use crate::line_string::LineString;
+use crate::rect::GeoRect;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl GeoCoord {
+    /// GeoJSON Point geometry of the coordinate.
+    pub fn to_geojson(&self) -> String {
+        format!(r#"{{"type":"Point","coordinates":{}}}"#, position(self))
+    }
+}
+
+impl GeoRect {
+    /// GeoJSON Polygon geometry of the rectangle, or a MultiPolygon of its two pieces if it
+    /// crosses the dateline.
+    pub fn to_geojson(&self) -> String {
+        let polygons: Vec<String> = self.to_polygon_rings().iter().map(|ring| format!("[{}]", positions(ring))).collect();
+
+        if let [polygon] = polygons.as_slice() {
+            format!(r#"{{"type":"Polygon","coordinates":{}}}"#, polygon)
+        } else {
+            format!(r#"{{"type":"MultiPolygon","coordinates":[{}]}}"#, polygons.join(","))
+        }
+    }
+}
+
+impl LineString {
+    /// GeoJSON LineString geometry of the line.
+    pub fn to_geojson(&self) -> String {
+        format!(r#"{{"type":"LineString","coordinates":{}}}"#, positions(self.coords()))
+    }
+}
+
+fn position(coord: &GeoCoord) -> String {
+    format!("[{},{}]", coord.lon(), coord.lat())
+}
+
+fn positions(coords: &[GeoCoord]) -> String {
+    format!("[{}]", coords.iter().map(position).collect::<Vec<_>>().join(","))
+}
+
+#[cfg(test)]
+mod geojson_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn coord_to_geojson() {
+        assert_eq!(coord(2.2945, 48.858222).to_geojson(), r#"{"type":"Point","coordinates":[2.2945,48.858222]}"#);
+        assert_eq!(coord(-10.0, 0.5).to_geojson(), r#"{"type":"Point","coordinates":[-10,0.5]}"#);
+    }
+
+    #[test]
+    fn rect_to_geojson() {
+        let rect = GeoRect::from_bbox(-10.0, -5.0, 10.0, 5.0).unwrap();
+        assert_eq!(
+            rect.to_geojson(),
+            r#"{"type":"Polygon","coordinates":[[[-10,-5],[10,-5],[10,5],[-10,5],[-10,-5]]]}"#
+        );
+
+        let crossing = GeoRect::from_bbox(170.0, -5.0, -170.0, 5.0).unwrap();
+        assert_eq!(
+            crossing.to_geojson(),
+            concat!(
+                r#"{"type":"MultiPolygon","coordinates":["#,
+                r#"[[[170,-5],[180,-5],[180,5],[170,5],[170,-5]]],"#,
+                r#"[[[-180,-5],[-170,-5],[-170,5],[-180,5],[-180,-5]]]"#,
+                r#"]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn line_string_to_geojson() {
+        let line = LineString::new(vec![coord(0.0, 0.0), coord(1.5, -2.25)]);
+        assert_eq!(line.to_geojson(), r#"{"type":"LineString","coordinates":[[0,0],[1.5,-2.25]]}"#);
+
+        let json: serde_json::Value = serde_json::from_str(&line.to_geojson()).unwrap();
+        assert_eq!(json["coordinates"][1][1], -2.25);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::coord::GeoCoord;
+
+use core::convert::TryFrom;
+use core::fmt;
+use h3o::{CellIndex, LatLng, Resolution};
+#[cfg(feature = "std")]
+use std::error;
+
+/// Finest H3 resolution, with cells of about 0.9 m².
+pub const MAX_H3_RESOLUTION: u8 = 15;
+
+impl GeoCoord {
+    /// Index of the H3 cell containing the coordinate at the given resolution, which is clamped
+    /// to `MAX_H3_RESOLUTION`.
+    pub fn to_h3(&self, resolution: u8) -> u64 {
+        let resolution = Resolution::try_from(resolution.min(MAX_H3_RESOLUTION)).unwrap();
+        let lat_lng = LatLng::new(self.lat(), self.lon()).unwrap();
+
+        u64::from(lat_lng.to_cell(resolution))
+    }
+
+    /// Center of the H3 cell with the given index.
+    pub fn from_h3(cell: u64) -> Result<GeoCoord, InvalidH3Cell> {
+        let center = LatLng::from(CellIndex::try_from(cell).map_err(|_| InvalidH3Cell)?);
+
+        Ok(GeoCoord::clamped(center.lng(), center.lat()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidH3Cell;
+
+impl fmt::Display for InvalidH3Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid H3 cell index given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidH3Cell {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod h3_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn to_h3() {
+        let san_francisco = coord(-122.388903, 37.769377);
+        assert_eq!(san_francisco.to_h3(9), 0x0892_8308_2e73_ffff);
+
+        // Resolutions above the maximum are clamped
+        assert_eq!(san_francisco.to_h3(20), san_francisco.to_h3(MAX_H3_RESOLUTION));
+    }
+
+    #[test]
+    fn from_h3() -> Result<(), InvalidH3Cell> {
+        let san_francisco = coord(-122.388903, 37.769377);
+        let cell = san_francisco.to_h3(9);
+        let center = GeoCoord::from_h3(cell)?;
+
+        // Resolution 9 cells have an edge length of about 200 m
+        assert!(center.haversine_distance(&san_francisco) < 200.0);
+        assert_eq!(center.to_h3(9), cell);
+
+        assert_eq!(GeoCoord::from_h3(0), Err(InvalidH3Cell));
+        assert_eq!(GeoCoord::from_h3(u64::MAX), Err(InvalidH3Cell));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,30 @@
-pub mod coord;
-pub mod mercator;
-pub mod rect;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature has to be enabled for floating point math");
+
+extern crate alloc;
+
+pub mod coord;
+pub mod dms;
+pub mod ecef;
+pub mod geohash;
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "h3")]
+pub mod h3;
+pub mod line_string;
+pub mod maidenhead;
+pub mod mercator;
+pub mod mgrs;
+pub mod plus_code;
+pub mod polygon;
+pub mod rect;
+pub mod utm;
+mod wkt;
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(not(feature = "std"))]
+mod math;
@@ -0,0 +1,182 @@
+use crate::coord::GeoCoord;
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+use crate::rect::GeoRect;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Sequence of coordinates joined by great-circle segments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineString {
+    coords: Vec<GeoCoord>,
+}
+
+impl LineString {
+    pub fn new(coords: Vec<GeoCoord>) -> LineString {
+        LineString { coords }
+    }
+
+    pub fn coords(&self) -> &[GeoCoord] {
+        &self.coords
+    }
+
+    pub fn into_coords(self) -> Vec<GeoCoord> {
+        self.coords
+    }
+
+    /// Sum of the haversine distances between consecutive coordinates in meters.
+    pub fn length_m(&self) -> f64 {
+        self.coords.windows(2).map(|pair| pair[0].haversine_distance(&pair[1])).sum()
+    }
+
+    /// Minimal bounding rectangle of the coordinates, or `None` if the line is empty.
+    pub fn bbox(&self) -> Option<GeoRect> {
+        GeoRect::from_coords(self.coords.iter().copied())
+    }
+
+    /// Simplified line using the Douglas–Peucker algorithm.
+    ///
+    /// Coordinates closer than `tolerance_m` meters to the great circle through the kept
+    /// neighbours are dropped. The end points are always kept, and lines with fewer than three
+    /// coordinates are returned unchanged.
+    pub fn simplify(&self, tolerance_m: f64) -> LineString {
+        if self.coords.len() < 3 {
+            return self.clone();
+        }
+
+        let mut keep = vec![false; self.coords.len()];
+        let mut ranges = vec![(0, self.coords.len() - 1)];
+
+        keep[0] = true;
+        keep[self.coords.len() - 1] = true;
+
+        while let Some((first, last)) = ranges.pop() {
+            let (start, end) = (&self.coords[first], &self.coords[last]);
+
+            let mut farthest = (first, 0.0);
+
+            for i in first + 1..last {
+                let distance = segment_distance(&self.coords[i], start, end);
+
+                if distance > farthest.1 {
+                    farthest = (i, distance);
+                }
+            }
+
+            if farthest.1 > tolerance_m {
+                keep[farthest.0] = true;
+                ranges.push((first, farthest.0));
+                ranges.push((farthest.0, last));
+            }
+        }
+
+        self.coords
+            .iter()
+            .zip(keep)
+            .filter_map(|(coord, keep)| if keep { Some(*coord) } else { None })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Line with great-circle points inserted so that no segment is longer than
+    /// `max_segment_m` meters.
+    ///
+    /// The original coordinates are kept as they are. A non-positive `max_segment_m` leaves the
+    /// line unchanged.
+    pub fn densify(&self, max_segment_m: f64) -> LineString {
+        if self.coords.is_empty() || max_segment_m <= 0.0 {
+            return self.clone();
+        }
+
+        let mut coords = vec![self.coords[0]];
+
+        for pair in self.coords.windows(2) {
+            let segments = (pair[0].haversine_distance(&pair[1]) / max_segment_m).ceil() as usize;
+
+            if segments > 1 {
+                let path = pair[0].great_circle_path(&pair[1], segments);
+                coords.extend_from_slice(&path[1..segments]);
+            }
+
+            coords.push(pair[1]);
+        }
+
+        LineString::new(coords)
+    }
+}
+
+impl From<Vec<GeoCoord>> for LineString {
+    fn from(coords: Vec<GeoCoord>) -> LineString {
+        LineString::new(coords)
+    }
+}
+
+/// Distance of `coord` to the great circle through `start` and `end`, or to `start` if the two
+/// coincide and thus do not define a great circle.
+fn segment_distance(coord: &GeoCoord, start: &GeoCoord, end: &GeoCoord) -> f64 {
+    if start == end {
+        coord.haversine_distance(start)
+    } else {
+        coord.cross_track_distance(start, end).abs()
+    }
+}
+
+#[cfg(test)]
+mod line_string_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn length_m() {
+        let one_degree = crate::coord::EARTH_MEAN_RADIUS.to_radians();
+        let line = LineString::new(vec![coord(0.0, 0.0), coord(0.0, 1.0), coord(0.0, 3.0), coord(0.0, 0.0)]);
+        assert!((line.length_m() - 6.0 * one_degree).abs() < 1e-6);
+
+        assert_eq!(LineString::new(Vec::new()).length_m(), 0.0);
+        assert_eq!(LineString::new(vec![coord(1.0, 2.0)]).length_m(), 0.0);
+    }
+
+    #[test]
+    fn bbox() {
+        let line = LineString::new(vec![coord(170.0, -5.0), coord(-175.0, 10.0), coord(179.0, 2.0)]);
+        assert_eq!(line.bbox(), Some(GeoRect::from_bbox(170.0, -5.0, -175.0, 10.0).unwrap()));
+
+        assert_eq!(LineString::new(Vec::new()).bbox(), None);
+    }
+
+    #[test]
+    fn simplify() {
+        let straight = LineString::new(coord(-20.0, 10.0).great_circle_path(&coord(30.0, 40.0), 50));
+        let simplified = straight.simplify(1.0);
+        assert_eq!(simplified.coords(), &[straight.coords()[0], straight.coords()[50]]);
+
+        let detour = LineString::new(vec![coord(0.0, 0.0), coord(1.0, 0.001), coord(2.0, 1.0), coord(3.0, 0.0), coord(4.0, 0.0)]);
+        assert_eq!(detour.simplify(1_000.0), detour);
+        assert_eq!(detour.simplify(60_000.0).coords(), &[coord(0.0, 0.0), coord(2.0, 1.0), coord(4.0, 0.0)]);
+        assert_eq!(detour.simplify(200_000.0).coords(), &[coord(0.0, 0.0), coord(4.0, 0.0)]);
+
+        // Closed rings keep the point farthest from their start
+        let ring = LineString::new(vec![coord(0.0, 0.0), coord(1.0, 0.0), coord(1.0, 1.0), coord(0.0, 0.0)]);
+        assert_eq!(ring.simplify(1.0).coords().len(), 4);
+
+        let short = LineString::new(vec![coord(0.0, 0.0), coord(1.0, 1.0)]);
+        assert_eq!(short.simplify(1e9), short);
+    }
+
+    #[test]
+    fn densify() {
+        let line = LineString::new(vec![coord(-10.0, 60.0), coord(50.0, 70.0), coord(50.0, 69.0), coord(179.0, 0.0)]);
+        let dense = line.densify(100_000.0);
+
+        assert!(dense.coords().windows(2).all(|pair| pair[0].haversine_distance(&pair[1]) <= 100_000.0 + 1e-6));
+        assert!(line.coords().iter().all(|coord| dense.coords().contains(coord)));
+        assert!((dense.length_m() - line.length_m()).abs() < 1e-3);
+
+        assert_eq!(line.densify(0.0), line);
+        assert_eq!(line.densify(1e9), line);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::coord::GeoCoord;
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+/// Number of divisions of each successive pair: fields, squares, subsquares and extended squares.
+const DIVISIONS: [u32; 4] = [18, 10, 24, 10];
+
+/// Most pairs of a locator supported, giving a resolution of 30" × 15".
+pub const MAX_MAIDENHEAD_PAIRS: usize = 4;
+
+impl GeoCoord {
+    /// Maidenhead locator of the coordinate with 1 to `MAX_MAIDENHEAD_PAIRS` pairs, e.g. `JN18eu`.
+    pub fn to_maidenhead(&self, pairs: usize) -> String {
+        let pairs = pairs.clamp(1, MAX_MAIDENHEAD_PAIRS);
+
+        // Index of the finest cell of the locator along each axis, split into pairs below
+        let cells: u32 = DIVISIONS[..pairs].iter().product();
+        let cell_index = |fraction: f64| ((fraction * f64::from(cells)).floor() as u32).min(cells - 1);
+
+        let lon = cell_index((self.lon() + 180.0) / 360.0);
+        let lat = cell_index((self.lat() + 90.0) / 180.0);
+        let mut locator = String::with_capacity(2 * pairs);
+        let mut remaining = cells;
+
+        for &divisions in &DIVISIONS[..pairs] {
+            remaining /= divisions;
+
+            locator.push(maidenhead_char(lon / remaining % divisions, divisions));
+            locator.push(maidenhead_char(lat / remaining % divisions, divisions));
+        }
+
+        locator
+    }
+
+    /// Center of the grid square of a Maidenhead locator, ignoring case.
+    pub fn from_maidenhead(locator: &str) -> Result<GeoCoord, InvalidMaidenhead> {
+        let locator = locator.as_bytes();
+
+        if locator.is_empty() || !locator.len().is_multiple_of(2) || locator.len() > 2 * MAX_MAIDENHEAD_PAIRS {
+            return Err(InvalidMaidenhead);
+        }
+
+        let (mut lon, mut lat) = (-180.0, -90.0);
+        let (mut lon_size, mut lat_size) = (360.0, 180.0);
+
+        for (pair, &divisions) in locator.chunks(2).zip(&DIVISIONS) {
+            lon_size /= f64::from(divisions);
+            lat_size /= f64::from(divisions);
+
+            lon += f64::from(maidenhead_index(pair[0], divisions)?) * lon_size;
+            lat += f64::from(maidenhead_index(pair[1], divisions)?) * lat_size;
+        }
+
+        GeoCoord::from_degrees(lon + lon_size / 2.0, lat + lat_size / 2.0).map_err(|_| InvalidMaidenhead)
+    }
+}
+
+fn maidenhead_char(index: u32, divisions: u32) -> char {
+    let base = match divisions {
+        10 => b'0',
+        18 => b'A',
+        _ => b'a',
+    };
+
+    (base + index as u8) as char
+}
+
+fn maidenhead_index(c: u8, divisions: u32) -> Result<u32, InvalidMaidenhead> {
+    let index = match divisions {
+        10 => c.wrapping_sub(b'0'),
+        _ => c.to_ascii_lowercase().wrapping_sub(b'a'),
+    };
+
+    if u32::from(index) < divisions {
+        Ok(u32::from(index))
+    } else {
+        Err(InvalidMaidenhead)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidMaidenhead;
+
+impl fmt::Display for InvalidMaidenhead {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid Maidenhead locator given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidMaidenhead {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod maidenhead_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn to_maidenhead() {
+        let paris = coord(2.3522, 48.8566);
+        assert_eq!(paris.to_maidenhead(1), "JN");
+        assert_eq!(paris.to_maidenhead(2), "JN18");
+        assert_eq!(paris.to_maidenhead(3), "JN18eu");
+        assert_eq!(paris.to_maidenhead(4), "JN18eu25");
+        assert_eq!(paris.to_maidenhead(0), "JN");
+        assert_eq!(paris.to_maidenhead(9), "JN18eu25");
+
+        // The ARRL headquarters in Newington, Connecticut
+        assert_eq!(coord(-72.727, 41.714).to_maidenhead(3), "FN31pr");
+
+        assert_eq!(coord(-180.0, -90.0).to_maidenhead(3), "AA00aa");
+        assert_eq!(coord(180.0, 90.0).to_maidenhead(3), "RR99xx");
+    }
+
+    #[test]
+    fn from_maidenhead() -> Result<(), InvalidMaidenhead> {
+        assert!(GeoCoord::from_maidenhead("JN")?.approx_eq(&coord(10.0, 45.0), 1e-9));
+        assert!(GeoCoord::from_maidenhead("JN18")?.approx_eq(&coord(3.0, 48.5), 1e-9));
+        assert!(GeoCoord::from_maidenhead("JN18eu")?.approx_eq(&coord(2.375, 48.854_166_667), 1e-9));
+        assert_eq!(GeoCoord::from_maidenhead("jn18EU")?, GeoCoord::from_maidenhead("JN18eu")?);
+        assert!(GeoCoord::from_maidenhead("JN18eu25")?.approx_eq(&coord(2.3522, 48.8566), 1e-2));
+
+        for invalid in &["", "J", "JN1", "SN18", "JS18", "JNA8", "JN18yu", "JN18eu2x", "JN18eu25aa"] {
+            assert_eq!(GeoCoord::from_maidenhead(invalid), Err(InvalidMaidenhead), "{}", invalid);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), InvalidMaidenhead> {
+        for &(lon, lat) in &[(2.3522, 48.8566), (2.35, 48.85), (-72.727, 41.714), (151.2093, -33.8688), (-179.99, -89.99), (179.99, 89.99)] {
+            for pairs in 1..=MAX_MAIDENHEAD_PAIRS {
+                let locator = coord(lon, lat).to_maidenhead(pairs);
+                assert_eq!(GeoCoord::from_maidenhead(&locator)?.to_maidenhead(pairs), locator);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,52 @@
 use crate::rect::{GeoRect, Edge};
-use crate::coord::{GeoCoord, TileCoord};
+use crate::coord::{hilbert_index, spread_bits, wrap_lon, GeoCoord, InvalidGeoCoord, TileCoord, WGS84_SEMI_MAJOR_AXIS};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::f64::consts::PI;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::error;
-use std::f64::consts::PI;
-use std::fmt;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Latitude limit of the Web Mercator projection, at which the square map ends.
+pub const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_779_806_59;
+
+impl GeoCoord {
+    /// Projects the coordinate to Web Mercator (EPSG:3857) x/y meters.
+    ///
+    /// Latitudes beyond the limits of the projection are clamped.
+    pub fn to_web_mercator(&self) -> (f64, f64) {
+        let lat = self.lat().clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE).to_radians();
+
+        let x = WGS84_SEMI_MAJOR_AXIS * self.lon_rad();
+        let y = WGS84_SEMI_MAJOR_AXIS * (PI / 4.0 + lat / 2.0).tan().ln();
+
+        (x, y)
+    }
+
+    /// Unprojects Web Mercator (EPSG:3857) x/y meters, clamping `x` to the dateline.
+    ///
+    /// Fails if either value is NaN.
+    pub fn from_web_mercator(x: f64, y: f64) -> Result<GeoCoord, InvalidGeoCoord> {
+        let lon = (x / WGS84_SEMI_MAJOR_AXIS).to_degrees().clamp(-180.0, 180.0);
+        let lat = (y / WGS84_SEMI_MAJOR_AXIS).sinh().atan().to_degrees();
+
+        GeoCoord::from_degrees(lon, lat)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "RawTileId"))]
 pub struct TileId {
     x: u32,
     y: u32,
@@ -14,7 +55,10 @@ pub struct TileId {
 
 impl TileId {
     pub fn new(x: u32, y: u32, z: u32) -> Result<TileId, InvalidTileId> {
-        let max = 2u32.pow(z);
+        let max = match 2u32.checked_pow(z) {
+            Some(max) => max,
+            None => return Err(InvalidTileId),
+        };
 
         if x < max && y < max {
             Ok(TileId { x, y, z })
@@ -35,17 +79,169 @@ impl TileId {
         self.z
     }
 
+    /// Tile one zoom level up containing this tile, or `None` at zoom level 0.
+    pub fn parent(&self) -> Option<TileId> {
+        if self.z == 0 {
+            None
+        } else {
+            Some(TileId { x: self.x / 2, y: self.y / 2, z: self.z - 1 })
+        }
+    }
+
+    /// The four tiles one zoom level down, ordered NW, NE, SW, SE.
+    ///
+    /// Panics if the tile is at zoom level 31, as its children would be out of range.
+    pub fn children(&self) -> [TileId; 4] {
+        assert!(self.z < 31, "tile at maximum zoom level has no children");
+
+        let (x, y, z) = (self.x * 2, self.y * 2, self.z + 1);
+
+        [
+            TileId { x, y, z },
+            TileId { x: x + 1, y, z },
+            TileId { x, y: y + 1, z },
+            TileId { x: x + 1, y: y + 1, z },
+        ]
+    }
+
+    /// Tiles at zoom level `z` covering the same area: the single ancestor for coarser levels,
+    /// all descendants in row-major order for finer ones. Levels above 31 yield no tiles.
+    pub fn at_zoom(&self, z: u32) -> Vec<TileId> {
+        if z <= self.z {
+            let shift = self.z - z;
+            vec![TileId { x: self.x >> shift, y: self.y >> shift, z }]
+        } else if z > 31 {
+            Vec::new()
+        } else {
+            let shift = z - self.z;
+            let side = 1 << shift;
+            let (min_x, min_y) = (self.x << shift, self.y << shift);
+
+            (min_y..min_y + side)
+                .flat_map(|y| (min_x..min_x + side).map(move |x| TileId { x, y, z }))
+                .collect()
+        }
+    }
+
+    /// Z-order curve index of the tile within its zoom level, x taking the even bits.
+    pub fn morton_index(&self) -> u64 {
+        spread_bits(self.x) | spread_bits(self.y) << 1
+    }
+
+    /// Hilbert curve index of the tile within its zoom level, starting at the top left tile.
+    ///
+    /// Consecutive indices always belong to adjacent tiles, unlike with `morton_index`.
+    pub fn hilbert_index(&self) -> u64 {
+        hilbert_index(self.x, self.y, self.z)
+    }
+
+    /// Substitutes the `{x}`, `{y}` and `{z}` placeholders of a slippy map URL template, and
+    /// `{-y}` with the flipped y of the TMS scheme.
+    pub fn format_url(&self, template: &str) -> String {
+        template
+            .replace("{x}", &self.x.to_string())
+            .replace("{y}", &self.y.to_string())
+            .replace("{-y}", &TmsTileId::from(*self).y().to_string())
+            .replace("{z}", &self.z.to_string())
+    }
+
+    /// Bing Maps quadkey of the tile, one base-4 digit per zoom level.
+    pub fn to_quadkey(&self) -> String {
+        (1..=self.z).rev().map(|i| {
+            let mask = 1 << (i - 1);
+            let digit = (self.x & mask != 0) as u8 + 2 * (self.y & mask != 0) as u8;
+            (b'0' + digit) as char
+        }).collect()
+    }
+
+    /// Parses a Bing Maps quadkey, the zoom level being the length of the key.
+    pub fn from_quadkey(quadkey: &str) -> Result<TileId, InvalidTileId> {
+        let z = quadkey.len() as u32;
+
+        if z > 31 {
+            return Err(InvalidTileId);
+        }
+
+        let mut x = 0;
+        let mut y = 0;
+
+        for c in quadkey.chars() {
+            let digit = match c {
+                '0'..='3' => c as u32 - '0' as u32,
+                _ => return Err(InvalidTileId),
+            };
+
+            x = x << 1 | digit & 1;
+            y = y << 1 | digit >> 1;
+        }
+
+        TileId::new(x, y, z)
+    }
+
     fn flip_y(&mut self) {
         self.y = 2u32.pow(self.z) - 1 - self.y
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Formats the tile as `z/x/y`, the usual slippy map order.
+impl fmt::Display for TileId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.z, self.x, self.y)
+    }
+}
+
+/// Parses a `z/x/y` triplet as produced by `Display`.
+impl FromStr for TileId {
+    type Err = InvalidTileId;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/').map(|part| part.parse::<u32>().map_err(|_| InvalidTileId));
+
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(z), Some(x), Some(y), None) => TileId::new(x?, y?, z?),
+            _ => Err(InvalidTileId),
+        }
+    }
+}
+
+/// Orders tiles along the Z-order curve, see `morton_index`, with ties broken by zoom level.
+impl Ord for TileId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.morton_index(), self.z).cmp(&(other.morton_index(), other.z))
+    }
+}
+
+impl PartialOrd for TileId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Unvalidated `TileId` used for deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawTileId {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<RawTileId> for TileId {
+    type Error = InvalidTileId;
+
+    fn try_from(raw: RawTileId) -> Result<Self, Self::Error> {
+        TileId::new(raw.x, raw.y, raw.z)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct TmsTileId(TileId);
 
 impl TmsTileId {
     pub fn new(x: u32, y: u32, z: u32) -> Result<TmsTileId, InvalidTileId> {
-        TileId::new(x, y, z).map(|tile_id| { TmsTileId { 0: tile_id } })
+        TileId::new(x, y, z).map(TmsTileId)
     }
 
     pub fn x(&self) -> u32 {
@@ -73,7 +269,7 @@ impl From<TileId> for TmsTileId {
     fn from(tile_id: TileId) -> Self {
         let mut t = tile_id;
         t.flip_y();
-        TmsTileId { 0: t }
+        TmsTileId(t)
     }
 }
 
@@ -86,88 +282,295 @@ impl fmt::Display for InvalidTileId {
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for InvalidTileId {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         None
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidTileGrid;
+
+impl fmt::Display for InvalidTileGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid tile grid given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidTileGrid {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
 pub struct TileGrid {
     z: u32,
     tile_extent: u32,
+    // Number of tiles along each axis and the width of the whole map in pixels, precomputed
+    // for the hot paths
+    count: f64,
+    map_size: f64,
 }
 
 impl TileGrid {
-    pub fn new(z: u32, tile_extent: u32) -> TileGrid {
-        // TODO: basic checks
-        TileGrid {
-            z, tile_extent
+    pub fn new(z: u32, tile_extent: u32) -> Result<TileGrid, InvalidTileGrid> {
+        if tile_extent == 0 || z > 31 {
+            Err(InvalidTileGrid)
+        } else {
+            let count = 2u32.pow(z) as f64;
+            Ok(TileGrid { z, tile_extent, count, map_size: tile_extent as f64 * count })
         }
     }
 
+    /// Highest zoom level up to `max_z` at which the bounding box fits into a viewport of the
+    /// given width and height in pixels, or 0 if it doesn't fit at any level.
+    pub fn best_zoom_for(bbox: &GeoRect, viewport_px: (u32, u32), tile_extent: u32, max_z: u32) -> u32 {
+        let mercator_y = |lat: f64| {
+            let lat_rad = lat.clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE).to_radians();
+            (1.0 - ((lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI)) / 2.0
+        };
+
+        // Extent of the bounding box as a fraction of the whole map
+        let width = bbox.width_degrees() / 360.0;
+        let height = mercator_y(bbox.bottom_right().lat()) - mercator_y(bbox.top_left().lat());
+
+        (0..=max_z.min(31))
+            .rev()
+            .find(|&z| {
+                let map_size = tile_extent as f64 * 2u32.pow(z) as f64;
+                width * map_size <= viewport_px.0 as f64 && height * map_size <= viewport_px.1 as f64
+            })
+            .unwrap_or(0)
+    }
+
+    pub fn z(&self) -> u32 {
+        self.z
+    }
+
+    pub fn tile_extent(&self) -> u32 {
+        self.tile_extent
+    }
+
+    /// Tile containing the coordinate and the pixel position within it.
+    ///
+    /// Latitudes beyond `MAX_MERCATOR_LATITUDE` are clamped, so coordinates closer to the poles
+    /// end up in the top or bottom row of tiles.
     pub fn tile_id(&self, coord: &GeoCoord) -> (TileId, TileCoord) {
-        let count = 2u32.pow(self.z) as f64;
+        self.split_pixel(pixel_coord(coord, self.map_size), self.count as i64)
+    }
+
+    /// `tile_id` of every coordinate, computing the constants of the grid only once.
+    pub fn tile_ids<'a, I: IntoIterator<Item = &'a GeoCoord>>(&self, coords: I) -> Vec<(TileId, TileCoord)> {
+        let count = self.count as i64;
 
-        let x = (coord.lon() + 180.0) / 360.0 * count;
+        coords.into_iter().map(|coord| self.split_pixel(pixel_coord(coord, self.map_size), count)).collect()
+    }
+
+    /// Tile containing an absolute pixel and the position within it.
+    ///
+    /// The right edge of the map at 180° wraps around to the leftmost column.
+    fn split_pixel(&self, (abs_x, abs_y): (i64, i64), count: i64) -> (TileId, TileCoord) {
+        let tile_extent = self.tile_extent as i64;
 
-        let lat_rad = coord.lat() * PI / 180.0;
-        let y = count * (1.0 - ((lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI)) / 2.0;
+        let x = abs_x.div_euclid(tile_extent);
+        let y = abs_y.div_euclid(tile_extent);
 
-        let tile_coord = TileCoord::new(
-            (x.fract() * self.tile_extent as f64).floor() as i32,
-            (y.fract() * self.tile_extent as f64).floor() as i32,
-        );
+        let tile_coord = TileCoord::new((abs_x - x * tile_extent) as i32, (abs_y - y * tile_extent) as i32);
+
+        (TileId { x: x.rem_euclid(count) as u32, y: y as u32, z: self.z }, tile_coord)
+    }
 
-        (TileId { x: x.floor() as u32, y: y.floor() as u32, z: self.z }, tile_coord)
+    /// Variant of `tile_id` for callers expecting a fallible conversion.
+    ///
+    /// Never fails, as `tile_id` always yields a valid tile, wrapping coordinates on the dateline
+    /// at 180° to the leftmost column of tiles.
+    pub fn try_tile_id(&self, coord: &GeoCoord) -> Result<(TileId, TileCoord), InvalidTileId> {
+        Ok(self.tile_id(coord))
     }
 
+    /// Pixel position of the coordinate relative to the top left corner of `tile_id`, which
+    /// may lie outside of the tile.
+    ///
+    /// Horizontally, the position is measured the shorter way around the globe from the center
+    /// of the tile, so a coordinate at 180° is at the left edge of the leftmost tile like in
+    /// `tile_id`. Latitudes beyond `MAX_MERCATOR_LATITUDE` are clamped like in `tile_id`.
     pub fn tile_coord(&self, coord: &GeoCoord, tile_id: TileId) -> TileCoord {
-        let count = 2u32.pow(self.z) as f64;
+        let (abs_x, abs_y) = self.pixel_coord(coord);
+        let tile_extent = self.tile_extent as i64;
+        let map_size = self.map_size as i64;
+
+        let west = tile_extent / 2 - map_size / 2;
+        let x = (abs_x - tile_id.x() as i64 * tile_extent - west).rem_euclid(map_size) + west;
+
+        TileCoord::new(x as i32, (abs_y - tile_id.y() as i64 * tile_extent) as i32)
+    }
 
-        let x = (coord.lon() + 180.0) / 360.0 * count;
+    /// Absolute pixel coordinate on the `tile_extent * 2^z` pixel wide map of the whole grid.
+    ///
+    /// Latitudes beyond `MAX_MERCATOR_LATITUDE` are clamped to the top and bottom rows of pixels,
+    /// while a longitude of 180° lies just past the right edge of the map.
+    pub fn pixel_coord(&self, coord: &GeoCoord) -> (i64, i64) {
+        pixel_coord(coord, self.map_size)
+    }
+
+    /// Coordinate of the top left corner of an absolute pixel, the inverse of `pixel_coord`.
+    ///
+    /// Pixels outside of the map are clamped to its edges.
+    pub fn pixel_to_coord(&self, x: i64, y: i64) -> GeoCoord {
+        let map_size = self.map_size;
 
-        let lat_rad = coord.lat() * PI / 180.0;
-        let y = count * (1.0 - ((lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI)) / 2.0;
+        let x = (x as f64).clamp(0.0, map_size);
+        let y = (y as f64).clamp(0.0, map_size);
 
-        let abs_x = (x * self.tile_extent as f64).floor() as i64;
-        let abs_y = (y * self.tile_extent as f64).floor() as i64;
+        let lon = x / map_size * 360.0 - 180.0;
+        let lat = (PI * (1.0 - 2.0 * y / map_size)).sinh().atan().to_degrees();
+
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    /// Meters per pixel at the given latitude.
+    pub fn ground_resolution(&self, lat: f64) -> f64 {
+        let lat = lat.clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE).to_radians();
+        let map_size = self.map_size;
+
+        lat.cos() * 2.0 * PI * WGS84_SEMI_MAJOR_AXIS / map_size
+    }
 
-        TileCoord::new(
-            (abs_x - tile_id.x() as i64 * self.tile_extent as i64) as i32,
-            (abs_y - tile_id.y() as i64 * self.tile_extent as i64) as i32,
-        )
+    /// Denominator of the map scale at the given latitude when displayed at `dpi` pixels per inch.
+    pub fn map_scale(&self, lat: f64, dpi: f64) -> f64 {
+        self.ground_resolution(lat) * dpi / 0.0254
     }
 
+    /// Tiles sharing an edge or a corner with `tile_id`, along with the touching edge.
+    ///
+    /// Columns wrap around the dateline. Beyond the top and bottom rows the neighbours are the
+    /// tiles across the pole: the tile half the globe away and its two neighbours, with left and
+    /// right swapped as the direction reverses over the pole. On low zoom levels where these
+    /// coincide, each tile is returned only once with its first matching edge, and the tile
+    /// itself is never returned.
     pub fn neighbours(&self, tile_id: TileId) -> Vec<(Edge, TileId)> {
-        let count = 2u32.pow(self.z);
+        let (neighbours, len) = self.neighbours_array(tile_id);
+        neighbours[..len].to_vec()
+    }
+
+    /// `neighbours` keyed by the touching edge, e.g. `Edge::TOP | Edge::RIGHT` for the tile to
+    /// the top right.
+    ///
+    /// On low zoom levels where neighbours coincide only their first edge is present.
+    pub fn neighbour_map(&self, tile_id: TileId) -> BTreeMap<Edge, TileId> {
+        let (neighbours, len) = self.neighbours_array(tile_id);
+        neighbours[..len].iter().copied().collect()
+    }
+
+    /// Allocation free variant of `neighbours`, only the first `len` entries of the returned
+    /// array are valid.
+    pub fn neighbours_array(&self, tile_id: TileId) -> ([(Edge, TileId); 8], usize) {
+        let count = self.count as u32;
 
-        let mut result = Vec::with_capacity(8);
+        let mut result = [(Edge::empty(), tile_id); 8];
+        let mut len = 0;
+
+        let mut push = |edge, neighbour: TileId| {
+            if neighbour != tile_id && !result[..len].iter().any(|&(_, t)| t == neighbour) {
+                result[len] = (edge, neighbour);
+                len += 1;
+            }
+        };
 
         let left_x = if tile_id.x > 0 { tile_id.x - 1 } else { count - 1 };
-        result.push((Edge::LEFT, TileId { x: left_x, ..tile_id }));
+        push(Edge::LEFT, TileId { x: left_x, ..tile_id });
 
         let right_x = if tile_id.x < count - 1 { tile_id.x + 1 } else { 0 };
-        result.push((Edge::RIGHT, TileId { x: right_x, ..tile_id }));
-        
-        if tile_id.y > 0 {
-            let top_y = tile_id.y - 1;
-            result.push((Edge::TOP, TileId { y: top_y, ..tile_id }));
-            result.push((Edge::TOP | Edge::LEFT, TileId { x: left_x, y: top_y, ..tile_id }));
-            result.push((Edge::TOP | Edge::RIGHT, TileId { x: right_x, y: top_y, ..tile_id }));
+        push(Edge::RIGHT, TileId { x: right_x, ..tile_id });
+
+        // Crossing a pole leads to the same row on the opposite side of the globe, facing the
+        // other way, so left and right are swapped there
+        let across_x = (tile_id.x + count / 2) % count;
+        let across_left_x = (across_x + 1) % count;
+        let across_right_x = (across_x + count - 1) % count;
+
+        let (top_y, top_left_x, top_x, top_right_x) = if tile_id.y > 0 {
+            (tile_id.y - 1, left_x, tile_id.x, right_x)
+        } else {
+            (0, across_left_x, across_x, across_right_x)
+        };
+
+        push(Edge::TOP, TileId { x: top_x, y: top_y, ..tile_id });
+        push(Edge::TOP | Edge::LEFT, TileId { x: top_left_x, y: top_y, ..tile_id });
+        push(Edge::TOP | Edge::RIGHT, TileId { x: top_right_x, y: top_y, ..tile_id });
+
+        let (bottom_y, bottom_left_x, bottom_x, bottom_right_x) = if tile_id.y < count - 1 {
+            (tile_id.y + 1, left_x, tile_id.x, right_x)
+        } else {
+            (tile_id.y, across_left_x, across_x, across_right_x)
+        };
+
+        push(Edge::BOTTOM, TileId { x: bottom_x, y: bottom_y, ..tile_id });
+        push(Edge::BOTTOM | Edge::LEFT, TileId { x: bottom_left_x, y: bottom_y, ..tile_id });
+        push(Edge::BOTTOM | Edge::RIGHT, TileId { x: bottom_right_x, y: bottom_y, ..tile_id });
+
+        (result, len)
+    }
+
+    /// Edge of `a` touching `b`, or `None` if the tiles aren't neighbours as returned by
+    /// `neighbours`. Diagonal neighbours share a corner, e.g. `Edge::TOP | Edge::LEFT`.
+    pub fn shared_edge(&self, a: TileId, b: TileId) -> Option<Edge> {
+        if a.z != self.z || b.z != self.z {
+            return None;
         }
 
-        if tile_id.y < count - 1 {
-            let bottom_y = tile_id.y + 1;
-            result.push((Edge::BOTTOM, TileId { y: bottom_y, ..tile_id }));
-            result.push((Edge::BOTTOM | Edge::LEFT, TileId { x: left_x, y: bottom_y, ..tile_id }));
-            result.push((Edge::BOTTOM | Edge::RIGHT, TileId { x: right_x, y: bottom_y, ..tile_id }));
+        let (neighbours, len) = self.neighbours_array(a);
+
+        neighbours[..len].iter().find(|&&(_, neighbour)| neighbour == b).map(|&(edge, _)| edge)
+    }
+
+    /// Tiles within a (2r+1)×(2r+1) square around `tile_id`, excluding the tile itself, in
+    /// row-major order.
+    ///
+    /// Columns wrap across the dateline like in `neighbours`, each tile being returned only once
+    /// even if the square is wider than the grid. Rows beyond the poles are omitted.
+    pub fn neighbours_within(&self, tile_id: TileId, radius: u32) -> Vec<TileId> {
+        let count = self.count as i64;
+        let radius = radius as i64;
+
+        let (min_dx, max_dx) = if 2 * radius + 1 >= count {
+            (-(tile_id.x as i64), count - 1 - tile_id.x as i64)
+        } else {
+            (-radius, radius)
+        };
+
+        let min_y = (tile_id.y as i64 - radius).max(0);
+        let max_y = (tile_id.y as i64 + radius).min(count - 1);
+
+        let mut result = Vec::new();
+
+        for y in min_y..=max_y {
+            for dx in min_dx..=max_dx {
+                let x = (tile_id.x as i64 + dx).rem_euclid(count);
+
+                if x != tile_id.x as i64 || y != tile_id.y as i64 {
+                    result.push(TileId { x: x as u32, y: y as u32, z: self.z });
+                }
+            }
         }
 
         result
     }
 
+    /// Geographic center of the tile, at the middle of its projected extent.
+    pub fn tile_center(&self, tile_id: TileId) -> GeoCoord {
+        let count = self.count;
+
+        let lon = (tile_id.x() as f64 + 0.5) * 360.0 / count - 180.0;
+        let lat = ((PI * (1.0 - 2.0 * (tile_id.y() as f64 + 0.5) / count)).sinh()).atan() * 180.0 / PI;
+
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
     pub fn tile_bbox(&self, tile_id: TileId) -> GeoRect {
-        let count = 2u32.pow(self.z) as f64;
+        let count = self.count;
 
         let left = tile_id.x() as f64 * 360.0 / count - 180.0;
         let top = ((PI * (1.0 - 2.0 * tile_id.y() as f64 / count)).sinh()).atan() * 180.0 / PI;
@@ -175,62 +578,191 @@ impl TileGrid {
         let tl = GeoCoord::from_degrees(left, top).unwrap();
 
         let right = left + (360.0 / count);
-        let bottom = if tile_id.y() == 2u32.pow(self.z) {
-            -85.05113 // valami okosabbat
+
+        // The bottom edge of the last row lands on -MAX_MERCATOR_LATITUDE by itself
+        let bottom = ((PI * (1.0 - 2.0 * (tile_id.y() + 1) as f64 / count)).sinh()).atan() * 180.0 / PI;
+
+        let br = GeoCoord::from_degrees(right, bottom).unwrap();
+
+        GeoRect::new(tl, br).unwrap()
+    }
+
+    /// Bounding box of the tile grown by `buf` times the tile extent on every side, rounded
+    /// down to whole pixels.
+    ///
+    /// Buffers reaching past the dateline wrap around it, resulting in a crossing rectangle, and
+    /// a buffer wider than the map covers all longitudes. Vertically the box is clamped to the
+    /// edges of the map at `MAX_MERCATOR_LATITUDE`.
+    pub fn tile_bbox_with_buf(&self, tile_id: TileId, buf: f64) -> GeoRect {
+        let abs_count = self.map_size;
+        let actual_buf = (buf.max(0.0) * self.tile_extent as f64).floor();
+
+        let tl_abs_x = tile_id.x() as f64 * self.tile_extent as f64 - actual_buf;
+        let tl_abs_y = (tile_id.y() as f64 * self.tile_extent as f64 - actual_buf).max(0.0);
+
+        let br_abs_x = (tile_id.x() + 1) as f64 * self.tile_extent as f64 + actual_buf;
+        let br_abs_y = ((tile_id.y() + 1) as f64 * self.tile_extent as f64 + actual_buf).min(abs_count);
+
+        let (left, right) = if br_abs_x - tl_abs_x >= abs_count {
+            (-180.0, 180.0)
         } else {
-            ((PI * (1.0 - 2.0 * (tile_id.y() + 1) as f64 / count)).sinh()).atan() * 180.0 / PI
+            (
+                wrap_lon(360.0 * (tl_abs_x / abs_count) - 180.0),
+                wrap_lon(360.0 * (br_abs_x / abs_count) - 180.0),
+            )
         };
 
+        let top = ((PI * (1.0 - 2.0 * (tl_abs_y / abs_count))).sinh()).atan() * 180.0 / PI;
+        let bottom = ((PI * (1.0 - 2.0 * (br_abs_y / abs_count))).sinh()).atan() * 180.0 / PI;
+
+        let tl = GeoCoord::from_degrees(left, top).unwrap();
         let br = GeoCoord::from_degrees(right, bottom).unwrap();
 
         GeoRect::new(tl, br).unwrap()
     }
 
-    pub fn tile_bbox_with_buf(&self, tile_id: TileId, buf: f64) -> GeoRect {
-        let count = 2u32.pow(self.z) as f64;
-        let abs_count = self.tile_extent as f64 * count;
+    /// Tiles touched by the given bounding box, see `TileRange`.
+    pub fn region(&self, bbox: &GeoRect) -> TileRange {
+        let count = self.count as u32;
+
+        // Unlike `tile_id`, an eastern edge at 180° stays in the rightmost column
+        let tile = |coord: &GeoCoord| {
+            let (x, y) = self.pixel_coord(coord);
+            let tile_extent = self.tile_extent as i64;
+
+            ((x / tile_extent).min(count as i64 - 1) as u32, (y / tile_extent).min(count as i64 - 1) as u32)
+        };
 
-        // TODO: handle dateline + poles
+        let (min_x, min_y) = tile(&bbox.top_left());
+        let (max_x, max_y) = tile(&bbox.bottom_right());
 
-        let actual_buf = (buf * self.tile_extent as f64) as u32;
+        let width = if bbox.crosses_dateline() {
+            (max_x + count - min_x + 1).min(count)
+        } else {
+            max_x - min_x + 1
+        };
 
-        let tl_abs_x = tile_id.x() * self.tile_extent - actual_buf;
-        let tl_abs_y = tile_id.y() * self.tile_extent - actual_buf;
+        TileRange { z: self.z, count, min_x, width, max_y, column: 0, y: min_y }
+    }
 
-        let br_abs_x = (tile_id.x() + 1) * self.tile_extent + actual_buf;
-        let br_abs_y = (tile_id.y() + 1) * self.tile_extent + actual_buf;
+    /// Tiles overlapping the bounding box on every zoom level from `min_z` to `max_z`, from
+    /// coarse to fine, each level as returned by `tiles_covering`. Levels above 31 are skipped.
+    pub fn pyramid(bbox: &GeoRect, min_z: u32, max_z: u32) -> impl Iterator<Item = TileId> {
+        let bbox = bbox.clone();
 
-        let left = 360.0 * (tl_abs_x as f64 / abs_count) - 180.0;
-        let top = ((PI * (1.0 - 2.0 * (tl_abs_y as f64 / abs_count))).sinh()).atan() * 180.0 / PI;
+        // The tile extent doesn't affect which tiles are covered
+        (min_z..=max_z.min(31)).flat_map(move |z| TileGrid::new(z, 256).unwrap().tiles_covering(&bbox))
+    }
 
-        let right = 360.0 * (br_abs_x as f64 / abs_count) - 180.0;
-        let bottom = ((PI * (1.0 - 2.0 * (br_abs_y as f64 / abs_count))).sinh()).atan() * 180.0 / PI;
+    /// Every tile overlapping the bounding box in row-major order, wrapping from the rightmost
+    /// column back to column 0 for boxes crossing the dateline.
+    pub fn tiles_covering(&self, bbox: &GeoRect) -> Vec<TileId> {
+        self.region(bbox).collect()
+    }
+}
 
-        /*
-        let left = tile_id.x() as f64 * 360.0 / count - 180.0;
-        let top = ((PI * (1.0 - 2.0 * tile_id.y() as f64 / count)).sinh()).atan() * 180.0 / PI;
+/// Absolute pixel coordinate on a map `map_size` pixels wide, see `TileGrid::pixel_coord`.
+fn pixel_coord(coord: &GeoCoord, map_size: f64) -> (i64, i64) {
+    let x = (coord.lon() + 180.0) / 360.0 * map_size;
 
-        let tl = Coord::from_degrees(left, top).unwrap();
+    let lat_rad = coord.lat().clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE).to_radians();
+    let y = map_size * (1.0 - ((lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI)) / 2.0;
 
-        let right = left + (360.0 / count);
-        let bottom = if tile_id.y() == 2u32.pow(self.z) {
-            -85.05113 // valami okosabbat
+    // The bottom edge of the map belongs to the last row of pixels
+    (x.floor() as i64, (y.floor() as i64).clamp(0, map_size as i64 - 1))
+}
+
+/// Iterator over a rectangular range of tiles in row-major order.
+///
+/// Ranges crossing the dateline wrap from the rightmost column back to column 0.
+#[derive(Clone, Debug)]
+pub struct TileRange {
+    z: u32,
+    count: u32,
+    min_x: u32,
+    width: u32,
+    max_y: u32,
+    column: u32,
+    y: u32,
+}
+
+impl Iterator for TileRange {
+    type Item = TileId;
+
+    fn next(&mut self) -> Option<TileId> {
+        if self.y > self.max_y {
+            return None;
+        }
+
+        let tile_id = TileId { x: (self.min_x + self.column) % self.count, y: self.y, z: self.z };
+
+        self.column += 1;
+
+        if self.column == self.width {
+            self.column = 0;
+            self.y += 1;
+        }
+
+        Some(tile_id)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.y > self.max_y {
+            0
         } else {
-            ((PI * (1.0 - 2.0 * (tile_id.y() + 1) as f64 / count)).sinh()).atan() * 180.0 / PI
+            u64::from(self.max_y - self.y + 1) * u64::from(self.width) - u64::from(self.column)
         };
-        */
 
-        let tl = GeoCoord::from_degrees(left, top).unwrap();
-        let br = GeoCoord::from_degrees(right, bottom).unwrap();
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
 
-        GeoRect::new(tl, br).unwrap()
+impl ExactSizeIterator for TileRange {}
+
+#[cfg(test)]
+mod web_mercator_tests {
+    use super::*;
+
+    fn assert_close(a: (f64, f64), b: (f64, f64), epsilon: f64) {
+        assert!((a.0 - b.0).abs() < epsilon && (a.1 - b.1).abs() < epsilon, "{:?} != {:?}", a, b);
     }
 
-    pub fn region(&self, bbox: &GeoRect) -> std::ops::RangeInclusive<TileId> {
-        let tl = self.tile_id(&bbox.top_left());
-        let br = self.tile_id(&bbox.bottom_right());
+    #[test]
+    fn projection() -> Result<(), crate::coord::InvalidGeoCoord> {
+        assert_close(GeoCoord::from_degrees(0.0, 0.0)?.to_web_mercator(), (0.0, 0.0), 1e-6);
+        assert_close(GeoCoord::from_degrees(-74.0060, 40.7128)?.to_web_mercator(), (-8_238_310.24, 4_970_071.58), 0.01);
+        assert_close(GeoCoord::from_degrees(2.2945, 48.858222)?.to_web_mercator(), (255_422.57, 6_250_838.78), 0.01);
+        assert_close(GeoCoord::from_degrees(180.0, 0.0)?.to_web_mercator(), (20_037_508.34, 0.0), 0.01);
+
+        assert_close(GeoCoord::from_degrees(0.0, 90.0)?.to_web_mercator(), (0.0, 20_037_508.34), 0.01);
+        assert_close(GeoCoord::from_degrees(0.0, -90.0)?.to_web_mercator(), (0.0, -20_037_508.34), 0.01);
 
-        tl.0 ..= br.0
+        Ok(())
+    }
+
+    #[test]
+    fn unprojection() -> Result<(), InvalidGeoCoord> {
+        let new_york = GeoCoord::from_degrees(-74.0060, 40.7128)?;
+        let (x, y) = new_york.to_web_mercator();
+        let unprojected = GeoCoord::from_web_mercator(x, y)?;
+        assert_close((unprojected.lon(), unprojected.lat()), (new_york.lon(), new_york.lat()), 1e-9);
+
+        let corner = GeoCoord::from_web_mercator(-20_037_508.342_789_244, 20_037_508.342_789_244)?;
+        assert_close((corner.lon(), corner.lat()), (-180.0, 85.051_128_779_806_59), 1e-9);
+
+        let beyond = GeoCoord::from_web_mercator(30_000_000.0, 1e9)?;
+        assert_close((beyond.lon(), beyond.lat()), (180.0, 90.0), 1e-9);
+
+        let infinite = GeoCoord::from_web_mercator(f64::NEG_INFINITY, f64::INFINITY)?;
+        assert_close((infinite.lon(), infinite.lat()), (-180.0, 90.0), 1e-9);
+
+        assert_eq!(GeoCoord::from_web_mercator(f64::NAN, 0.0), Err(InvalidGeoCoord));
+        assert_eq!(GeoCoord::from_web_mercator(0.0, f64::NAN), Err(InvalidGeoCoord));
+
+        Ok(())
     }
 }
 
@@ -243,7 +775,10 @@ mod tile_id_tests {
         assert_eq!(TileId::new(0, 2, 1), Err(InvalidTileId));
         assert_eq!(TileId::new(5, 2, 2), Err(InvalidTileId));
 
+        assert_eq!(TileId::new(0, 0, 32), Err(InvalidTileId));
+
         assert!(TileId::new(12, 31, 5).is_ok());
+        assert!(TileId::new(0, 0, 31).is_ok());
     }
 
     #[test]
@@ -256,4 +791,683 @@ mod tile_id_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn hash() -> Result<(), InvalidTileId> {
+        use std::collections::{HashMap, HashSet};
+
+        let mut cache = HashMap::new();
+        cache.insert(TileId::new(12, 31, 5)?, "a");
+        cache.insert(TileId::new(31, 12, 5)?, "b");
+        cache.insert(TileId::new(12, 31, 5)?, "c");
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&TileId::new(12, 31, 5)?), Some(&"c"));
+        assert_eq!(cache.get(&TileId::new(12, 31, 6)?), None);
+
+        let tms_tiles: HashSet<_> = vec![TmsTileId::new(12, 0, 5)?, TmsTileId::from(TileId::new(12, 31, 5)?)].into_iter().collect();
+        assert_eq!(tms_tiles.len(), 1);
+        assert!(tms_tiles.contains(&TmsTileId::new(12, 0, 5)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn morton_order() -> Result<(), InvalidTileId> {
+        assert_eq!(TileId::new(0, 0, 3)?.morton_index(), 0);
+        assert_eq!(TileId::new(1, 0, 3)?.morton_index(), 1);
+        assert_eq!(TileId::new(0, 1, 3)?.morton_index(), 2);
+        assert_eq!(TileId::new(3, 5, 3)?.morton_index(), 0b100111);
+        assert_eq!(TileId::new(7, 7, 3)?.morton_index(), 63);
+
+        let mut tiles = TileId::new(0, 0, 0)?.at_zoom(3);
+        tiles.reverse();
+        tiles.sort();
+
+        assert!(tiles.windows(2).all(|pair| pair[0].morton_index() < pair[1].morton_index()));
+        assert_eq!(&tiles[..4], &TileId::new(0, 0, 2)?.at_zoom(3)[..]);
+
+        assert!(TileId::new(0, 0, 3)? < TileId::new(0, 0, 4)?);
+        assert!(TileId::new(1, 0, 3)? > TileId::new(0, 0, 4)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hilbert_order() -> Result<(), InvalidTileId> {
+        assert_eq!(TileId::new(0, 0, 0)?.hilbert_index(), 0);
+        assert_eq!(TileId::new(0, 0, 1)?.hilbert_index(), 0);
+        assert_eq!(TileId::new(0, 1, 1)?.hilbert_index(), 1);
+        assert_eq!(TileId::new(1, 1, 1)?.hilbert_index(), 2);
+        assert_eq!(TileId::new(1, 0, 1)?.hilbert_index(), 3);
+
+        for z in &[2, 5, 8] {
+            let mut tiles = TileId::new(0, 0, 0)?.at_zoom(*z);
+            tiles.sort_by_key(TileId::hilbert_index);
+
+            assert!(tiles.iter().enumerate().all(|(i, tile_id)| tile_id.hilbert_index() == i as u64));
+            assert!(tiles.windows(2).all(|pair| {
+                let dx = (pair[0].x() as i64 - pair[1].x() as i64).abs();
+                let dy = (pair[0].y() as i64 - pair[1].y() as i64).abs();
+                dx + dy == 1
+            }));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_and_parse() -> Result<(), InvalidTileId> {
+        let tile_id = TileId::new(12, 31, 5)?;
+        assert_eq!(tile_id.to_string(), "5/12/31");
+        assert_eq!(tile_id.to_string().parse(), Ok(tile_id));
+        assert_eq!("0/0/0".parse(), TileId::new(0, 0, 0));
+
+        for invalid in &["4/16/0", "4/0/16", "32/0/0", "", "4/1", "4/1/2/3", "4/-1/2", "4/a/2", "4 /1/2", "4/1/2/"] {
+            assert_eq!(invalid.parse::<TileId>(), Err(InvalidTileId), "{}", invalid);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_url() -> Result<(), InvalidTileId> {
+        let tile_id = TileId::new(12, 3, 5)?;
+
+        assert_eq!(tile_id.format_url("https://tile.openstreetmap.org/{z}/{x}/{y}.png"), "https://tile.openstreetmap.org/5/12/3.png");
+        assert_eq!(tile_id.format_url("https://example.com/tms/{z}/{x}/{-y}.png"), "https://example.com/tms/5/12/28.png");
+        assert_eq!(tile_id.format_url("{x}-{x}-{y}"), "12-12-3");
+        assert_eq!(tile_id.format_url("no placeholders"), "no placeholders");
+
+        Ok(())
+    }
+
+    #[test]
+    fn parent() -> Result<(), InvalidTileId> {
+        assert_eq!(TileId::new(0, 0, 0)?.parent(), None);
+        assert_eq!(TileId::new(1, 1, 1)?.parent(), Some(TileId::new(0, 0, 0)?));
+        assert_eq!(TileId::new(13, 6, 5)?.parent(), Some(TileId::new(6, 3, 4)?));
+
+        let tile_id = TileId::new(2154, 1400, 12)?;
+        let parent = tile_id.parent().unwrap();
+        assert_eq!(TileId::new(parent.x(), parent.y(), parent.z()), Ok(parent));
+
+        let parent_bbox = TileGrid::new(11, 256).unwrap().tile_bbox(parent);
+        let child_bbox = TileGrid::new(12, 256).unwrap().tile_bbox(tile_id);
+        assert!(parent_bbox.contains_rect(&child_bbox));
+
+        Ok(())
+    }
+
+    #[test]
+    fn children() -> Result<(), InvalidTileId> {
+        let tile_id = TileId::new(6, 3, 4)?;
+        let [nw, ne, sw, se] = tile_id.children();
+        assert_eq!(nw, TileId::new(12, 6, 5)?);
+        assert_eq!(ne, TileId::new(13, 6, 5)?);
+        assert_eq!(sw, TileId::new(12, 7, 5)?);
+        assert_eq!(se, TileId::new(13, 7, 5)?);
+
+        for child in tile_id.children().iter() {
+            assert_eq!(child.parent(), Some(tile_id));
+        }
+
+        let parent_bbox = TileGrid::new(4, 256).unwrap().tile_bbox(tile_id);
+
+        let grid = TileGrid::new(5, 256).unwrap();
+        let (nw, ne, sw, se) = (grid.tile_bbox(nw), grid.tile_bbox(ne), grid.tile_bbox(sw), grid.tile_bbox(se));
+
+        assert_eq!(nw.top_left(), parent_bbox.top_left());
+        assert_eq!(se.bottom_right(), parent_bbox.bottom_right());
+        assert_eq!(nw.bottom_right(), se.top_left());
+        assert_eq!(ne.top_left().lon(), nw.bottom_right().lon());
+        assert_eq!(ne.bottom_right().lat(), nw.bottom_right().lat());
+        assert_eq!(sw.top_left().lat(), nw.bottom_right().lat());
+        assert_eq!(sw.bottom_right().lon(), nw.bottom_right().lon());
+
+        let total_area = nw.area_m2() + ne.area_m2() + sw.area_m2() + se.area_m2();
+        assert!((total_area - parent_bbox.area_m2()).abs() / total_area < 1e-12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn at_zoom() -> Result<(), InvalidTileId> {
+        let tile_id = TileId::new(6, 3, 4)?;
+
+        assert_eq!(tile_id.at_zoom(4), vec![tile_id]);
+        assert_eq!(tile_id.at_zoom(3), vec![tile_id.parent().unwrap()]);
+        assert_eq!(tile_id.at_zoom(0), vec![TileId::new(0, 0, 0)?]);
+        assert_eq!(tile_id.at_zoom(5), tile_id.children().to_vec());
+
+        for z in 4..10 {
+            let descendants = tile_id.at_zoom(z);
+            assert_eq!(descendants.len(), 4usize.pow(z - 4));
+            assert!(descendants.iter().all(|d| d.at_zoom(4) == vec![tile_id]));
+        }
+
+        assert!(tile_id.at_zoom(32).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn quadkey() -> Result<(), InvalidTileId> {
+        assert_eq!(TileId::new(3, 5, 3)?.to_quadkey(), "213");
+        assert_eq!(TileId::from_quadkey("213")?, TileId::new(3, 5, 3)?);
+
+        let tile_id = TileId::new(2154, 1400, 12)?;
+        assert_eq!(tile_id.to_quadkey(), "120203323010");
+        assert_eq!(TileId::from_quadkey(&tile_id.to_quadkey())?, tile_id);
+
+        assert_eq!(TileId::new(0, 0, 0)?.to_quadkey(), "");
+        assert_eq!(TileId::from_quadkey("")?, TileId::new(0, 0, 0)?);
+
+        assert_eq!(TileId::from_quadkey("1204"), Err(InvalidTileId));
+        assert_eq!(TileId::from_quadkey("12a3"), Err(InvalidTileId));
+        assert_eq!(TileId::from_quadkey(&"1".repeat(32)), Err(InvalidTileId));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), InvalidTileId> {
+        let tile_id = TileId::new(12, 31, 5)?;
+        let json = serde_json::to_string(&tile_id).unwrap();
+        assert_eq!(json, r#"{"x":12,"y":31,"z":5}"#);
+        assert_eq!(serde_json::from_str::<TileId>(&json).unwrap(), tile_id);
+
+        let tms_tile_id = TmsTileId::new(12, 31, 5)?;
+        assert_eq!(serde_json::to_string(&tms_tile_id).unwrap(), json);
+        assert_eq!(serde_json::from_str::<TmsTileId>(&json).unwrap(), tms_tile_id);
+
+        assert!(serde_json::from_str::<TileId>(r#"{"x":32,"y":0,"z":5}"#).is_err());
+        assert!(serde_json::from_str::<TmsTileId>(r#"{"x":32,"y":0,"z":5}"#).is_err());
+        assert!(serde_json::from_str::<TileId>(r#"{"x":0,"y":2,"z":1}"#).is_err());
+        assert!(serde_json::from_str::<TileId>(r#"{"x":0,"y":0,"z":40}"#).is_err());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tile_grid_tests {
+    use super::*;
+    use crate::test_utils::XorShift64;
+
+    fn rect(tl: (f64, f64), br: (f64, f64)) -> GeoRect {
+        GeoRect::new(GeoCoord::from_degrees(tl.0, tl.1).unwrap(), GeoCoord::from_degrees(br.0, br.1).unwrap()).unwrap()
+    }
+
+    fn tile(x: u32, y: u32, z: u32) -> TileId {
+        TileId::new(x, y, z).unwrap()
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(TileGrid::new(4, 0).err(), Some(InvalidTileGrid));
+        assert_eq!(TileGrid::new(32, 256).err(), Some(InvalidTileGrid));
+
+        let grid = TileGrid::new(31, 256).unwrap();
+        assert_eq!(grid.z(), 31);
+        assert_eq!(grid.tile_extent(), 256);
+    }
+
+    #[test]
+    fn cached_constants() {
+        let coords: Vec<_> = [(2.2945, 48.858222), (-180.0, 85.0), (179.999, -85.0), (0.0, 0.0)]
+            .iter()
+            .map(|&(lon, lat)| GeoCoord::from_degrees(lon, lat).unwrap())
+            .collect();
+
+        for z in 0..=31 {
+            let grid = TileGrid::new(z, 512).unwrap();
+            let count = 2u32.pow(z) as f64;
+
+            for coord in &coords {
+                let x = (coord.lon() + 180.0) / 360.0 * 512.0 * count;
+                let (tile_id, tile_coord) = grid.tile_id(coord);
+                assert_eq!(tile_id.x() as i64 * 512 + tile_coord.x as i64, x.floor() as i64);
+            }
+
+            let last = tile(2u32.pow(z) - 1, 0, z);
+            let left = last.x() as f64 * 360.0 / count - 180.0;
+            assert_eq!(grid.tile_bbox(last).top_left().lon(), left);
+            assert_eq!(grid.ground_resolution(0.0), 2.0 * PI * WGS84_SEMI_MAJOR_AXIS / (512.0 * count));
+        }
+    }
+
+    #[test]
+    fn mercator_latitude_limit() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let (top_tile, top_coord) = grid.tile_id(&GeoCoord::from_degrees(0.0, MAX_MERCATOR_LATITUDE).unwrap());
+        assert_eq!(top_tile.y(), 0);
+        assert_eq!(top_coord.y, 0);
+
+        let bottom = GeoCoord::from_degrees(0.0, -MAX_MERCATOR_LATITUDE).unwrap();
+        assert!((grid.tile_coord(&bottom, tile(8, 15, 4)).y - 256).abs() <= 1);
+
+        let bbox = grid.tile_bbox(tile(8, 0, 4));
+        assert!((bbox.top_left().lat() - MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+
+        let bbox = grid.tile_bbox(tile(8, 15, 4));
+        assert!((bbox.bottom_right().lat() + MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tile_bbox_bottom_row() {
+        for z in &[0, 1, 4, 12, 20] {
+            let grid = TileGrid::new(*z, 256).unwrap();
+            let last = 2u32.pow(*z) - 1;
+
+            let bbox = grid.tile_bbox(tile(last, last, *z));
+            assert!((bbox.bottom_right().lat() + MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+            assert!(bbox.top_left().lat() > bbox.bottom_right().lat());
+
+            let bbox = grid.tile_bbox(tile(0, 0, *z));
+            assert!((bbox.top_left().lat() - MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn tile_bbox_with_buf() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let bbox = grid.tile_bbox_with_buf(tile(5, 5, 4), 0.0);
+        assert_eq!(bbox, grid.tile_bbox(tile(5, 5, 4)));
+
+        // Half a tile, i.e. 11.25° on each side, wrapping across the dateline
+        let bbox = grid.tile_bbox_with_buf(tile(0, 5, 4), 0.5);
+        assert!(bbox.crosses_dateline());
+        assert!((bbox.top_left().lon() - 168.75).abs() < 1e-9);
+        assert!((bbox.bottom_right().lon() + 146.25).abs() < 1e-9);
+
+        let bbox = grid.tile_bbox_with_buf(tile(15, 5, 4), 0.5);
+        assert!(bbox.crosses_dateline());
+        assert!((bbox.top_left().lon() - 146.25).abs() < 1e-9);
+        assert!((bbox.bottom_right().lon() + 168.75).abs() < 1e-9);
+
+        let bbox = grid.tile_bbox_with_buf(tile(5, 0, 4), 0.25);
+        assert!((bbox.top_left().lat() - MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+        assert!(bbox.bottom_right().lat() < grid.tile_bbox(tile(5, 0, 4)).bottom_right().lat());
+
+        let bbox = grid.tile_bbox_with_buf(tile(5, 15, 4), 0.25);
+        assert!((bbox.bottom_right().lat() + MAX_MERCATOR_LATITUDE).abs() < 1e-9);
+
+        let grid = TileGrid::new(0, 256).unwrap();
+        let bbox = grid.tile_bbox_with_buf(tile(0, 0, 0), 0.1);
+        assert_eq!(bbox.to_bbox(), GeoRect::web_mercator_world().to_bbox());
+    }
+
+    #[test]
+    fn tile_id_beyond_mercator_limit() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let (tile_id, _) = grid.tile_id(&GeoCoord::from_degrees(10.0, 89.0).unwrap());
+        assert_eq!(tile_id, tile(8, 0, 4));
+
+        let (tile_id, _) = grid.tile_id(&GeoCoord::from_degrees(10.0, 90.0).unwrap());
+        assert_eq!(tile_id, tile(8, 0, 4));
+
+        let (tile_id, _) = grid.tile_id(&GeoCoord::from_degrees(10.0, -89.0).unwrap());
+        assert_eq!(tile_id, tile(8, 15, 4));
+
+        let (tile_id, _) = grid.tile_id(&GeoCoord::from_degrees(10.0, -90.0).unwrap());
+        assert_eq!(tile_id, tile(8, 15, 4));
+    }
+
+    #[test]
+    fn tile_id_matches_tile_coord() {
+        let mut rng = XorShift64::new(0x2545_F491_4F6C_DD1D);
+
+        for &(z, tile_extent) in &[(0, 256), (4, 256), (12, 512), (18, 256), (24, 4096)] {
+            let grid = TileGrid::new(z, tile_extent).unwrap();
+
+            for _ in 0..2_000 {
+                let coord = GeoCoord::from_degrees(rng.next_f64() * 360.0 - 180.0, rng.next_f64() * 180.0 - 90.0).unwrap();
+                let (tile_id, tile_coord) = grid.tile_id(&coord);
+
+                assert_eq!(grid.tile_coord(&coord, tile_id), tile_coord, "{:?}", coord);
+                assert!(tile_coord.is_within(tile_extent as i32), "{:?}", coord);
+            }
+
+            // Edges of the map, which random sampling won't hit
+            for &(lon, lat) in &[(180.0, 0.0), (0.0, -MAX_MERCATOR_LATITUDE), (180.0, -MAX_MERCATOR_LATITUDE), (-180.0, MAX_MERCATOR_LATITUDE), (0.0, -90.0)] {
+                let coord = GeoCoord::from_degrees(lon, lat).unwrap();
+                let (tile_id, tile_coord) = grid.tile_id(&coord);
+
+                assert_eq!(grid.tile_coord(&coord, tile_id), tile_coord, "{:?}", coord);
+                assert!(tile_coord.is_within(tile_extent as i32), "{:?}", coord);
+            }
+        }
+
+        // A coordinate at 180° is at the right edge of the rightmost tile
+        let grid = TileGrid::new(4, 256).unwrap();
+        let dateline = GeoCoord::from_degrees(180.0, 0.0).unwrap();
+        assert_eq!(grid.tile_coord(&dateline, tile(15, 8, 4)), TileCoord::new(256, 0));
+        assert_eq!(grid.tile_coord(&dateline, tile(0, 8, 4)), TileCoord::new(0, 0));
+
+        // Exactly on a tile boundary
+        let grid = TileGrid::new(4, 256).unwrap();
+        let boundary = GeoCoord::from_degrees(22.5, 0.0).unwrap();
+        assert_eq!(grid.tile_id(&boundary), (tile(9, 8, 4), TileCoord::new(0, 0)));
+        assert_eq!(grid.tile_coord(&boundary, tile(9, 8, 4)), TileCoord::new(0, 0));
+    }
+
+    #[test]
+    fn tile_ids() {
+        let coords: Vec<_> = [(2.2945, 48.858222), (-180.0, 90.0), (180.0, -90.0), (22.5, 0.0), (-74.006, 40.7128)]
+            .iter()
+            .map(|&(lon, lat)| GeoCoord::from_degrees(lon, lat).unwrap())
+            .collect();
+
+        for &(z, tile_extent) in &[(0, 256), (4, 256), (18, 512)] {
+            let grid = TileGrid::new(z, tile_extent).unwrap();
+            let individually: Vec<_> = coords.iter().map(|coord| grid.tile_id(coord)).collect();
+
+            assert_eq!(grid.tile_ids(&coords), individually);
+        }
+
+        assert!(TileGrid::new(4, 256).unwrap().tile_ids(&[]).is_empty());
+    }
+
+    #[test]
+    fn try_tile_id() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let dateline = GeoCoord::from_degrees(180.0, 10.0).unwrap();
+        let (tile_id, tile_coord) = grid.try_tile_id(&dateline).unwrap();
+        assert_eq!(tile_id, tile(0, 7, 4));
+        assert_eq!(tile_coord.x, 0);
+        assert_eq!(grid.try_tile_id(&GeoCoord::from_degrees(-180.0, 10.0).unwrap()), Ok((tile_id, tile_coord)));
+
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222).unwrap();
+        assert_eq!(grid.try_tile_id(&paris), Ok(grid.tile_id(&paris)));
+    }
+
+    #[test]
+    fn tile_id_on_dateline() {
+        for z in &[0, 3, 18] {
+            let grid = TileGrid::new(*z, 256).unwrap();
+            let dateline = GeoCoord::from_degrees(180.0, 0.0).unwrap();
+
+            let (tile_id, tile_coord) = grid.tile_id(&dateline);
+            assert_eq!(TileId::new(tile_id.x(), tile_id.y(), tile_id.z()), Ok(tile_id));
+            assert_eq!((tile_id.x(), tile_coord.x), (0, 0));
+
+            assert_eq!(grid.tile_ids(&[dateline]), vec![(tile_id, tile_coord)]);
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let neighbours = grid.neighbours(tile(5, 5, 4));
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.contains(&(Edge::TOP | Edge::LEFT, tile(4, 4, 4))));
+        assert!(neighbours.contains(&(Edge::BOTTOM, tile(5, 6, 4))));
+
+        let neighbours = grid.neighbours(tile(0, 0, 4));
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.contains(&(Edge::LEFT, tile(15, 0, 4))));
+        assert!(neighbours.contains(&(Edge::BOTTOM | Edge::LEFT, tile(15, 1, 4))));
+
+        for tile_id in &[tile(5, 5, 4), tile(0, 0, 4), tile(15, 15, 4), tile(15, 7, 4)] {
+            let (array, len) = grid.neighbours_array(*tile_id);
+            assert_eq!(array[..len].to_vec(), grid.neighbours(*tile_id));
+        }
+    }
+
+    #[test]
+    fn polar_neighbours() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let top_row = grid.neighbours(tile(3, 0, 4));
+        assert_eq!(top_row.len(), 8);
+        assert!(top_row.contains(&(Edge::TOP, tile(11, 0, 4))));
+        assert!(top_row.contains(&(Edge::TOP | Edge::LEFT, tile(12, 0, 4))));
+        assert!(top_row.contains(&(Edge::TOP | Edge::RIGHT, tile(10, 0, 4))));
+        assert!(top_row.contains(&(Edge::BOTTOM, tile(3, 1, 4))));
+
+        let bottom_row = grid.neighbours(tile(15, 15, 4));
+        assert_eq!(bottom_row.len(), 8);
+        assert!(bottom_row.contains(&(Edge::BOTTOM, tile(7, 15, 4))));
+        assert!(bottom_row.contains(&(Edge::BOTTOM | Edge::LEFT, tile(8, 15, 4))));
+        assert!(bottom_row.contains(&(Edge::BOTTOM | Edge::RIGHT, tile(6, 15, 4))));
+        assert!(bottom_row.contains(&(Edge::RIGHT, tile(0, 15, 4))));
+        assert!(bottom_row.contains(&(Edge::TOP | Edge::RIGHT, tile(0, 14, 4))));
+
+        // Crossing the pole back swaps left and right again
+        for &(edge, neighbour) in top_row.iter().filter(|(edge, _)| edge.contains(Edge::TOP)) {
+            let back_edge = if edge == Edge::TOP { edge } else { edge ^ (Edge::LEFT | Edge::RIGHT) };
+            assert!(grid.neighbours(neighbour).contains(&(back_edge, tile(3, 0, 4))));
+        }
+
+        let grid = TileGrid::new(1, 256).unwrap();
+        assert_eq!(grid.neighbours(tile(0, 0, 1)), vec![
+            (Edge::LEFT, tile(1, 0, 1)),
+            (Edge::BOTTOM, tile(0, 1, 1)),
+            (Edge::BOTTOM | Edge::LEFT, tile(1, 1, 1)),
+        ]);
+
+        let grid = TileGrid::new(0, 256).unwrap();
+        assert!(grid.neighbours(tile(0, 0, 0)).is_empty());
+    }
+
+    #[test]
+    fn neighbour_map() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let neighbours = grid.neighbour_map(tile(5, 5, 4));
+        assert_eq!(neighbours.len(), 8);
+        assert_eq!(neighbours.get(&(Edge::TOP | Edge::RIGHT)), Some(&tile(6, 4, 4)));
+        assert_eq!(neighbours.get(&(Edge::BOTTOM | Edge::LEFT)), Some(&tile(4, 6, 4)));
+        assert_eq!(neighbours.get(&Edge::LEFT), Some(&tile(4, 5, 4)));
+        assert_eq!(neighbours.get(&(Edge::LEFT | Edge::RIGHT)), None);
+
+        let grid = TileGrid::new(1, 256).unwrap();
+        assert_eq!(grid.neighbour_map(tile(0, 0, 1)).len(), 3);
+    }
+
+    #[test]
+    fn shared_edge() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(6, 5, 4)), Some(Edge::RIGHT));
+        assert_eq!(grid.shared_edge(tile(6, 5, 4), tile(5, 5, 4)), Some(Edge::LEFT));
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(5, 4, 4)), Some(Edge::TOP));
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(5, 6, 4)), Some(Edge::BOTTOM));
+
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(4, 4, 4)), Some(Edge::TOP | Edge::LEFT));
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(6, 4, 4)), Some(Edge::TOP | Edge::RIGHT));
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(4, 6, 4)), Some(Edge::BOTTOM | Edge::LEFT));
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(6, 6, 4)), Some(Edge::BOTTOM | Edge::RIGHT));
+
+        // Across the dateline
+        assert_eq!(grid.shared_edge(tile(0, 5, 4), tile(15, 5, 4)), Some(Edge::LEFT));
+        assert_eq!(grid.shared_edge(tile(15, 5, 4), tile(0, 6, 4)), Some(Edge::BOTTOM | Edge::RIGHT));
+
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(7, 5, 4)), None);
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(5, 5, 4)), None);
+        assert_eq!(grid.shared_edge(tile(5, 5, 4), tile(2, 2, 3)), None);
+    }
+
+    #[test]
+    fn neighbours_within() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let neighbours = grid.neighbours_within(tile(5, 5, 4), 2);
+        assert_eq!(neighbours.len(), 24);
+        assert!(!neighbours.contains(&tile(5, 5, 4)));
+        assert_eq!(neighbours.first(), Some(&tile(3, 3, 4)));
+        assert_eq!(neighbours.last(), Some(&tile(7, 7, 4)));
+
+        let mut direct_neighbours: Vec<_> = grid.neighbours(tile(5, 5, 4)).into_iter().map(|(_, t)| t).collect();
+        direct_neighbours.sort_by_key(|t| (t.y(), t.x()));
+        assert_eq!(grid.neighbours_within(tile(5, 5, 4), 1), direct_neighbours);
+
+        let wrapping = grid.neighbours_within(tile(0, 5, 4), 2);
+        assert_eq!(wrapping.len(), 24);
+        assert!(wrapping.contains(&tile(14, 3, 4)));
+        assert!(wrapping.contains(&tile(2, 7, 4)));
+
+        assert_eq!(grid.neighbours_within(tile(5, 0, 4), 2).len(), 14);
+        assert_eq!(grid.neighbours_within(tile(5, 5, 4), 0), vec![]);
+        assert_eq!(grid.neighbours_within(tile(5, 5, 4), 100).len(), 255);
+
+        let grid = TileGrid::new(1, 256).unwrap();
+        assert_eq!(grid.neighbours_within(tile(0, 0, 1), 1), vec![tile(1, 0, 1), tile(0, 1, 1), tile(1, 1, 1)]);
+    }
+
+    #[test]
+    fn ground_resolution() {
+        assert!((TileGrid::new(0, 256).unwrap().ground_resolution(0.0) - 156_543.03).abs() < 0.01);
+        assert!((TileGrid::new(10, 256).unwrap().ground_resolution(0.0) - 152.87).abs() < 0.01);
+        assert!((TileGrid::new(10, 512).unwrap().ground_resolution(0.0) - 76.44).abs() < 0.01);
+        assert!((TileGrid::new(10, 256).unwrap().ground_resolution(60.0) - 152.87 / 2.0).abs() < 0.01);
+
+        // Published values are derived from rounded resolutions, hence the relative tolerance
+        assert!((TileGrid::new(0, 256).unwrap().map_scale(0.0, 96.0) / 591_657_527.59 - 1.0).abs() < 1e-5);
+        assert!((TileGrid::new(10, 256).unwrap().map_scale(0.0, 96.0) / 577_790.55 - 1.0).abs() < 1e-5);
+        assert!((TileGrid::new(10, 256).unwrap().map_scale(0.0, 192.0) / 1_155_581.1 - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn pixel_coord() {
+        let grid = TileGrid::new(0, 256).unwrap();
+        assert_eq!(grid.pixel_coord(&GeoCoord::from_degrees(0.0, 0.0).unwrap()), (128, 128));
+        assert_eq!(grid.pixel_coord(&GeoCoord::from_degrees(-180.0, MAX_MERCATOR_LATITUDE).unwrap()), (0, 0));
+        assert_eq!(grid.pixel_coord(&GeoCoord::from_degrees(-180.0, 90.0).unwrap()), (0, 0));
+        assert!(grid.pixel_to_coord(0, 0).approx_eq(&GeoCoord::from_degrees(-180.0, MAX_MERCATOR_LATITUDE).unwrap(), 1e-12));
+        assert_eq!(grid.pixel_to_coord(128, 128), GeoCoord::from_degrees(0.0, 0.0).unwrap());
+        assert_eq!(grid.pixel_to_coord(-10, 300), grid.pixel_to_coord(0, 256));
+
+        let paris = GeoCoord::from_degrees(2.2945, 48.858222).unwrap();
+
+        for &(z, tile_extent) in &[(4, 256), (12, 512), (18, 256)] {
+            let grid = TileGrid::new(z, tile_extent).unwrap();
+
+            let (x, y) = grid.pixel_coord(&paris);
+
+            let (tile_id, tile_coord) = grid.tile_id(&paris);
+            assert_eq!(x, (tile_id.x() * tile_extent) as i64 + tile_coord.x as i64);
+
+            let corner = grid.pixel_to_coord(x, y);
+            let next_corner = grid.pixel_to_coord(x + 1, y + 1);
+            assert!(corner.lon() <= paris.lon() && paris.lon() < next_corner.lon());
+            assert!(corner.lat() >= paris.lat() && paris.lat() > next_corner.lat());
+
+            let pixel_center = GeoRect::new(corner, next_corner).unwrap().center();
+            assert_eq!(grid.pixel_coord(&pixel_center), (x, y));
+        }
+    }
+
+    #[test]
+    fn tile_center() {
+        let grid = TileGrid::new(0, 256).unwrap();
+        assert_eq!(grid.tile_center(tile(0, 0, 0)), GeoCoord::from_degrees(0.0, 0.0).unwrap());
+
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        for tile_id in &[tile(0, 0, 4), tile(8, 3, 4), tile(15, 15, 4)] {
+            let center = grid.tile_center(*tile_id);
+            let bbox = grid.tile_bbox(*tile_id);
+            assert!(bbox.contains_coord(&center));
+            assert_eq!(grid.tile_id(&center).0, *tile_id);
+
+            // Mercator stretches higher latitudes, moving the projected center poleward
+            if bbox.bottom_right().lat() >= 0.0 {
+                assert!(center.lat() > bbox.center().lat());
+            } else if bbox.top_left().lat() <= 0.0 {
+                assert!(center.lat() < bbox.center().lat());
+            }
+
+            assert_eq!(center.lon(), bbox.center().lon());
+        }
+
+        let (tile_id, tile_coord) = grid.tile_id(&grid.tile_center(tile(8, 3, 4)));
+        assert_eq!(tile_id, tile(8, 3, 4));
+        assert_eq!(tile_coord, TileCoord::new(128, 128));
+    }
+
+    #[test]
+    fn region() {
+        let grid = TileGrid::new(4, 256).unwrap();
+
+        let normal_region = grid.region(&rect((-10.0, 20.0), (10.0, -20.0)));
+        assert_eq!(normal_region.len(), 4);
+        assert_eq!(normal_region.collect::<Vec<_>>(), vec![tile(7, 7, 4), tile(8, 7, 4), tile(7, 8, 4), tile(8, 8, 4)]);
+
+        let crossing_region = grid.region(&rect((170.0, 10.0), (-170.0, -10.0)));
+        assert_eq!(crossing_region.collect::<Vec<_>>(), vec![tile(15, 7, 4), tile(0, 7, 4), tile(15, 8, 4), tile(0, 8, 4)]);
+
+        let single_tile_region = grid.region(&rect((1.0, 1.0), (2.0, 0.5)));
+        assert_eq!(single_tile_region.collect::<Vec<_>>(), vec![tile(8, 7, 4)]);
+
+        let world_region = grid.region(&rect((-180.0, 85.0), (180.0, -85.0)));
+        assert_eq!(world_region.count(), 256);
+
+        let wrapped_row_region = grid.region(&rect((10.0, 1.0), (5.0, 0.5)));
+        assert_eq!(wrapped_row_region.count(), 16);
+
+        // The number of tiles overflows `u32` at high zoom levels
+        if cfg!(target_pointer_width = "64") {
+            let mut world_region = TileGrid::new(18, 256).unwrap().region(&GeoRect::world());
+            assert_eq!(world_region.len(), 1 << 36);
+
+            world_region.next();
+            assert_eq!(world_region.size_hint(), ((1 << 36) - 1, Some((1 << 36) - 1)));
+
+            let world_region = TileGrid::new(31, 256).unwrap().region(&GeoRect::world());
+            assert_eq!(world_region.len(), 1 << 62);
+        }
+    }
+
+    #[test]
+    fn best_zoom_for() {
+        let europe = rect((-25.0, 72.0), (45.0, 35.0));
+        assert_eq!(TileGrid::best_zoom_for(&europe, (1024, 768), 256, 18), 3);
+        assert_eq!(TileGrid::best_zoom_for(&europe, (2048, 1536), 256, 18), 4);
+        assert_eq!(TileGrid::best_zoom_for(&europe, (1024, 768), 512, 18), 2);
+
+        let paris = rect((2.22, 48.91), (2.47, 48.81));
+        assert_eq!(TileGrid::best_zoom_for(&paris, (1024, 768), 256, 18), 12);
+        assert_eq!(TileGrid::best_zoom_for(&paris, (1024, 768), 256, 10), 10);
+
+        assert_eq!(TileGrid::best_zoom_for(&GeoRect::world(), (100, 100), 256, 18), 0);
+        assert_eq!(TileGrid::best_zoom_for(&rect((1.0, 1.0), (1.0, 1.0)), (100, 100), 256, 40), 31);
+    }
+
+    #[test]
+    fn tiles_covering() {
+        let grid = TileGrid::new(3, 256).unwrap();
+
+        // Columns of 45°, rows split at ±41.0°, ±66.5° and ±79.2°
+        assert_eq!(grid.tiles_covering(&rect((-10.0, 50.0), (30.0, 35.0))).len(), 2 * 2);
+        assert_eq!(grid.tiles_covering(&rect((-100.0, 70.0), (100.0, -70.0))).len(), 6 * 6);
+        assert_eq!(grid.tiles_covering(&rect((1.0, 1.0), (2.0, 0.5))), vec![tile(4, 3, 3)]);
+
+        let crossing = grid.tiles_covering(&rect((100.0, 10.0), (-100.0, 5.0)));
+        assert_eq!(crossing, vec![tile(6, 3, 3), tile(7, 3, 3), tile(0, 3, 3), tile(1, 3, 3)]);
+
+        assert_eq!(grid.tiles_covering(&GeoRect::world()).len(), 64);
+    }
+
+    #[test]
+    fn pyramid() {
+        let bbox = rect((-10.0, 50.0), (30.0, 35.0));
+
+        let tiles: Vec<_> = TileGrid::pyramid(&bbox, 2, 6).collect();
+        let per_level: usize = (2..=6).map(|z| TileGrid::new(z, 256).unwrap().tiles_covering(&bbox).len()).sum();
+        assert_eq!(tiles.len(), per_level);
+
+        assert_eq!(tiles.first(), Some(&tile(1, 1, 2)));
+        assert!(tiles.windows(2).all(|pair| pair[0].z() <= pair[1].z()));
+
+        assert_eq!(TileGrid::pyramid(&GeoRect::world(), 0, 2).count(), 1 + 4 + 16);
+        assert_eq!(TileGrid::pyramid(&bbox, 3, 2).count(), 0);
+    }
 }
@@ -0,0 +1,190 @@
+use crate::coord::GeoCoord;
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+use crate::utm::{central_meridian, Hemisphere, UtmCoord, BANDS};
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+/// Column letters of the 100 km squares, cycling every three zones.
+const COLUMNS: [&[u8; 8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+
+/// Row letters of the 100 km squares, cycling every 2000 km of northing.
+const ROWS: &[u8; 20] = b"ABCDEFGHJKLMNPQRSTUV";
+
+/// Largest number of easting/northing digits, giving a resolution of 1 m.
+pub const MAX_MGRS_PRECISION: usize = 5;
+
+impl GeoCoord {
+    /// Military Grid Reference System reference of the 1 m to 100 km square containing the
+    /// coordinate, e.g. `31UDQ4825111935` with a `precision` of 5 easting/northing digits,
+    /// capped at `MAX_MGRS_PRECISION`.
+    ///
+    /// Returns `None` for the polar regions, which are covered by UPS instead of UTM.
+    pub fn to_mgrs(&self, precision: usize) -> Option<String> {
+        let precision = precision.min(MAX_MGRS_PRECISION);
+        let (zone, band) = self.utm_zone();
+
+        if !BANDS.contains(&(band as u8)) {
+            return None;
+        }
+
+        let utm = self.to_utm_zone(zone);
+        let column = (utm.easting / 100_000.0).floor() as usize;
+        let row = (utm.northing / 100_000.0).floor() as usize;
+
+        let column_letter = COLUMNS[(zone as usize - 1) % 3][(column - 1).min(7)] as char;
+        let row_letter = ROWS[(row + if zone.is_multiple_of(2) { 5 } else { 0 }) % 20] as char;
+
+        let mut mgrs = format!("{}{}{}{}", zone, band, column_letter, row_letter);
+
+        if precision > 0 {
+            let divisor = 10f64.powi((MAX_MGRS_PRECISION - precision) as i32);
+            let easting = (utm.easting.rem_euclid(100_000.0) / divisor).floor() as u32;
+            let northing = (utm.northing.rem_euclid(100_000.0) / divisor).floor() as u32;
+
+            mgrs += &format!("{:0width$}{:0width$}", easting, northing, width = precision);
+        }
+
+        Some(mgrs)
+    }
+
+    /// Center of the square referenced by an MGRS string, optionally containing spaces, e.g.
+    /// `31U DQ 48251 11935`.
+    pub fn from_mgrs(mgrs: &str) -> Result<GeoCoord, InvalidMgrs> {
+        let mgrs: String = mgrs.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_ascii_uppercase();
+        let zone_len = mgrs.bytes().take_while(u8::is_ascii_digit).count();
+
+        if !mgrs.is_ascii() || !(1..=2).contains(&zone_len) || mgrs.len() < zone_len + 3 {
+            return Err(InvalidMgrs);
+        }
+
+        let zone: u8 = mgrs[..zone_len].parse().map_err(|_| InvalidMgrs)?;
+        let letters = &mgrs.as_bytes()[zone_len..zone_len + 3];
+        let digits = &mgrs[zone_len + 3..];
+
+        if !(1..=60).contains(&zone) || !digits.len().is_multiple_of(2) || digits.len() > 2 * MAX_MGRS_PRECISION || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InvalidMgrs);
+        }
+
+        let band = BANDS.iter().position(|&b| b == letters[0]).ok_or(InvalidMgrs)?;
+        let column = COLUMNS[(zone as usize - 1) % 3].iter().position(|&b| b == letters[1]).ok_or(InvalidMgrs)?;
+        let row = ROWS.iter().position(|&b| b == letters[2]).ok_or(InvalidMgrs)?;
+        let row = (row + 20 - if zone.is_multiple_of(2) { 5 } else { 0 }) % 20;
+
+        let precision = digits.len() / 2;
+        let size = 10f64.powi((MAX_MGRS_PRECISION - precision) as i32);
+        let parse = |digits: &str| if digits.is_empty() { Ok(0.0) } else { digits.parse::<f64>().map_err(|_| InvalidMgrs) };
+
+        let easting = (column + 1) as f64 * 100_000.0 + parse(&digits[..precision])? * size + size / 2.0;
+        let mut northing = row as f64 * 100_000.0 + parse(&digits[precision..])? * size + size / 2.0;
+
+        // The row letters repeat every 2000 km, so the band determines the actual northing. South
+        // of the equator, parallels dip to their lowest northing at the edges of the zone.
+        let band_south = band as f64 * 8.0 - 80.0;
+        let hemisphere = if band_south < 0.0 { Hemisphere::South } else { Hemisphere::North };
+        let band_start = [-3.0, 0.0, 3.0].iter()
+            .map(|offset| GeoCoord::from_degrees(central_meridian(zone) + offset, band_south).unwrap().to_utm_zone(zone).northing)
+            .fold(f64::INFINITY, f64::min);
+        let min_northing = (band_start / 100_000.0).floor() * 100_000.0;
+
+        while northing < min_northing {
+            northing += 2_000_000.0;
+        }
+
+        GeoCoord::from_utm(&UtmCoord { zone, hemisphere, easting, northing }).map_err(|_| InvalidMgrs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidMgrs;
+
+impl fmt::Display for InvalidMgrs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid MGRS reference given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidMgrs {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod mgrs_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn to_mgrs() {
+        assert_eq!(coord(-93.0, 42.0).to_mgrs(5).unwrap(), "15TWG0000049776");
+        assert_eq!(coord(2.2945, 48.858222).to_mgrs(5).unwrap(), "31UDQ4825111935");
+        assert_eq!(coord(151.2093, -33.8688).to_mgrs(5).unwrap(), "56HLH3436850948");
+
+        assert_eq!(coord(2.2945, 48.858222).to_mgrs(3).unwrap(), "31UDQ482119");
+        assert_eq!(coord(2.2945, 48.858222).to_mgrs(1).unwrap(), "31UDQ41");
+        assert_eq!(coord(2.2945, 48.858222).to_mgrs(0).unwrap(), "31UDQ");
+        assert_eq!(coord(2.2945, 48.858222).to_mgrs(9), coord(2.2945, 48.858222).to_mgrs(5));
+
+        assert_eq!(coord(0.0, 85.0).to_mgrs(5), None);
+        assert_eq!(coord(0.0, -81.0).to_mgrs(5), None);
+    }
+
+    #[test]
+    fn from_mgrs() -> Result<(), InvalidMgrs> {
+        let center = GeoCoord::from_mgrs("15TWG0000049776")?;
+        assert!(center.approx_eq(&coord(-93.0, 42.0), 1e-5));
+
+        let eiffel_tower = coord(2.2945, 48.858222);
+        assert!(GeoCoord::from_mgrs("31U DQ 48251 11935")?.approx_eq(&eiffel_tower, 1e-5));
+        assert!(GeoCoord::from_mgrs("31udq4825111935")?.approx_eq(&eiffel_tower, 1e-5));
+        assert!(GeoCoord::from_mgrs("31UDQ41")?.approx_eq(&eiffel_tower, 0.1));
+        assert!(GeoCoord::from_mgrs("31UDQ")?.approx_eq(&eiffel_tower, 1.0));
+
+        assert!(GeoCoord::from_mgrs("56HLH3436850948")?.approx_eq(&coord(151.2093, -33.8688), 1e-5));
+
+        assert_eq!(GeoCoord::from_mgrs(""), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UDQ482"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("61UDQ4811"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31IDQ4811"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UJQ4811"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UDW4811"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UDQ48x1"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UDQ123456789012"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UDé1"), Err(InvalidMgrs));
+        assert_eq!(GeoCoord::from_mgrs("31UDQ4é1"), Err(InvalidMgrs));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), InvalidMgrs> {
+        for &(lon, lat) in &[(-93.0, 42.0), (5.3, 60.4), (15.6, 78.2), (-70.0, -0.5), (-70.0, 0.5), (120.0, -79.5), (179.9, 83.5), (-179.9, 1.0), (20.0, -45.0)] {
+            let original = coord(lon, lat);
+            let mgrs = original.to_mgrs(5).unwrap();
+            let round_trip = GeoCoord::from_mgrs(&mgrs)?;
+
+            assert!(round_trip.approx_eq(&original, 1e-4), "{} {:?} != {:?}", mgrs, round_trip, original);
+            assert_eq!(round_trip.to_mgrs(5).unwrap(), mgrs);
+        }
+
+        // Southern parallels dip below the band's northing on the central meridian towards the
+        // edges of the zone
+        for &(lon, lat) in &[(-179.95, -63.998), (-174.05, -63.998), (3.05, -39.999), (8.95, -15.999), (-122.95, -71.999)] {
+            let original = coord(lon, lat);
+            let round_trip = GeoCoord::from_mgrs(&original.to_mgrs(5).unwrap())?;
+
+            assert!(round_trip.approx_eq(&original, 1e-4), "{:?} != {:?}", round_trip, original);
+        }
+
+        Ok(())
+    }
+}
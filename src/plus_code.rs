@@ -0,0 +1,296 @@
+use crate::coord::GeoCoord;
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+const ALPHABET: &[u8; 20] = b"23456789CFGHJMPQRVWX";
+
+const SEPARATOR: u8 = b'+';
+const SEPARATOR_POSITION: usize = 8;
+const PADDING: u8 = b'0';
+
+/// Number of digits encoded as latitude/longitude pairs, beyond which grid refinement is used.
+const PAIR_CODE_LENGTH: usize = 10;
+
+/// Resolution of each grid refinement digit, splitting a cell into 5 rows and 4 columns.
+const GRID_ROWS: i64 = 5;
+const GRID_COLUMNS: i64 = 4;
+
+/// Number of finest grid cells per degree of latitude/longitude.
+const LAT_PRECISION: i64 = 8000 * 3125;
+const LON_PRECISION: i64 = 8000 * 1024;
+
+/// Length of the plus codes commonly used for addresses, with a resolution of about 14 m.
+pub const DEFAULT_PLUS_CODE_LENGTH: usize = 10;
+
+/// Longest plus code produced, with a resolution of a few millimeters.
+pub const MAX_PLUS_CODE_LENGTH: usize = 15;
+
+impl GeoCoord {
+    /// Open Location Code ("plus code") of the coordinate with `length` digits, e.g.
+    /// `8FVC9G8F+6X`.
+    ///
+    /// The length is clamped to 2..=`MAX_PLUS_CODE_LENGTH`, and odd lengths below the 10 digits
+    /// of latitude/longitude pairs are rounded up. Codes shorter than 8 digits are padded with
+    /// zeros.
+    pub fn to_plus_code(&self, length: usize) -> String {
+        let length = length.clamp(2, MAX_PLUS_CODE_LENGTH);
+        let length = if length < PAIR_CODE_LENGTH && length % 2 == 1 { length + 1 } else { length };
+
+        let mut lat = (((self.lat() + 90.0) * LAT_PRECISION as f64 * 1e6).round() / 1e6).floor() as i64;
+        let mut lon = (((self.lon() + 180.0) * LON_PRECISION as f64 * 1e6).round() / 1e6).floor() as i64;
+
+        lat = lat.clamp(0, 180 * LAT_PRECISION - 1);
+        lon = lon.rem_euclid(360 * LON_PRECISION);
+
+        let mut digits = [0u8; MAX_PLUS_CODE_LENGTH];
+
+        for digit in digits[PAIR_CODE_LENGTH..].iter_mut().rev() {
+            *digit = ALPHABET[((lat % GRID_ROWS) * GRID_COLUMNS + lon % GRID_COLUMNS) as usize];
+            lat /= GRID_ROWS;
+            lon /= GRID_COLUMNS;
+        }
+
+        for pair in digits[..PAIR_CODE_LENGTH].chunks_mut(2).rev() {
+            pair[0] = ALPHABET[(lat % 20) as usize];
+            pair[1] = ALPHABET[(lon % 20) as usize];
+            lat /= 20;
+            lon /= 20;
+        }
+
+        let mut code = String::with_capacity(length.max(SEPARATOR_POSITION) + 1);
+
+        for (i, &digit) in digits.iter().enumerate().take(length.max(SEPARATOR_POSITION)) {
+            if i == SEPARATOR_POSITION {
+                code.push(SEPARATOR as char);
+            }
+
+            code.push(if i < length { digit } else { PADDING } as char);
+        }
+
+        if length <= SEPARATOR_POSITION {
+            code.push(SEPARATOR as char);
+        }
+
+        code
+    }
+
+    /// Center of the area of a full plus code, including zero padded ones like `8FVC0000+`.
+    pub fn from_plus_code(code: &str) -> Result<GeoCoord, InvalidPlusCode> {
+        let digits = plus_code_digits(code)?;
+
+        if digits.len() < 2 || digits[0] >= 9 || digits[1] >= 18 {
+            return Err(InvalidPlusCode);
+        }
+
+        let (south, west, lat_size, lon_size) = decode_digits(&digits);
+        let lat = (south + lat_size / 2.0).min(90.0);
+        let lon = west + lon_size / 2.0;
+
+        GeoCoord::from_degrees(if lon > 180.0 { lon - 360.0 } else { lon }, lat).map_err(|_| InvalidPlusCode)
+    }
+
+    /// Center of the area of a short plus code with leading digits removed, like `9G8F+6X`,
+    /// recovered as the nearest matching area to `reference`. Full codes are decoded as is.
+    pub fn from_short_plus_code(code: &str, reference: &GeoCoord) -> Result<GeoCoord, InvalidPlusCode> {
+        let separator = code.find(SEPARATOR as char).ok_or(InvalidPlusCode)?;
+
+        if separator >= SEPARATOR_POSITION {
+            return GeoCoord::from_plus_code(code);
+        }
+
+        if separator % 2 == 1 || code.contains(PADDING as char) {
+            return Err(InvalidPlusCode);
+        }
+
+        let missing = SEPARATOR_POSITION - separator;
+        let prefix = reference.to_plus_code(PAIR_CODE_LENGTH);
+        let mut full_code = String::from(&prefix[..missing]);
+        full_code.push_str(code);
+
+        let center = GeoCoord::from_plus_code(&full_code)?;
+
+        // Size of the area identified by the missing digits
+        let resolution = 20f64.powi(2 - (missing / 2) as i32);
+        let half = resolution / 2.0;
+
+        let mut lat = center.lat();
+        let mut lon = center.lon();
+
+        if reference.lat() + half < lat && lat - resolution >= -90.0 {
+            lat -= resolution;
+        } else if reference.lat() - half > lat && lat + resolution <= 90.0 {
+            lat += resolution;
+        }
+
+        if reference.lon() + half < lon {
+            lon -= resolution;
+        } else if reference.lon() - half > lon {
+            lon += resolution;
+        }
+
+        GeoCoord::from_degrees_normalized(lon, lat).map_err(|_| InvalidPlusCode)
+    }
+}
+
+/// Digit values of a full plus code, validating the placement of the separator and padding.
+fn plus_code_digits(code: &str) -> Result<Vec<u8>, InvalidPlusCode> {
+    let code = code.as_bytes();
+
+    if code.iter().filter(|&&c| c == SEPARATOR).count() != 1 || code.get(SEPARATOR_POSITION) != Some(&SEPARATOR) {
+        return Err(InvalidPlusCode);
+    }
+
+    let (before, after) = (&code[..SEPARATOR_POSITION], &code[SEPARATOR_POSITION + 1..]);
+    let padding = before.iter().position(|&c| c == PADDING).unwrap_or(SEPARATOR_POSITION);
+
+    if padding < SEPARATOR_POSITION {
+        if padding % 2 == 1 || before[padding..].iter().any(|&c| c != PADDING) || !after.is_empty() {
+            return Err(InvalidPlusCode);
+        }
+    } else if after.len() == 1 || after.len() > MAX_PLUS_CODE_LENGTH - SEPARATOR_POSITION {
+        return Err(InvalidPlusCode);
+    }
+
+    before[..padding].iter().chain(after).map(|&c| {
+        ALPHABET.iter().position(|&a| a == c.to_ascii_uppercase()).map(|i| i as u8).ok_or(InvalidPlusCode)
+    }).collect()
+}
+
+/// South-west corner and size in degrees of the area of the given digit values.
+fn decode_digits(digits: &[u8]) -> (f64, f64, f64, f64) {
+    let (mut south, mut west) = (-90.0, -180.0);
+    let (mut lat_size, mut lon_size) = (400.0, 400.0);
+
+    for pair in digits.iter().take(PAIR_CODE_LENGTH).collect::<Vec<_>>().chunks(2) {
+        lat_size /= 20.0;
+        lon_size /= 20.0;
+        south += f64::from(*pair[0]) * lat_size;
+
+        if let Some(&&lon) = pair.get(1) {
+            west += f64::from(lon) * lon_size;
+        }
+    }
+
+    for &digit in digits.iter().skip(PAIR_CODE_LENGTH) {
+        lat_size /= GRID_ROWS as f64;
+        lon_size /= GRID_COLUMNS as f64;
+        south += f64::from(digit / GRID_COLUMNS as u8) * lat_size;
+        west += f64::from(digit % GRID_COLUMNS as u8) * lon_size;
+    }
+
+    (south, west, lat_size, lon_size)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidPlusCode;
+
+impl fmt::Display for InvalidPlusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid plus code given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidPlusCode {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod plus_code_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn to_plus_code() {
+        let zurich = coord(8.524_997, 47.365_590);
+        assert_eq!(zurich.to_plus_code(DEFAULT_PLUS_CODE_LENGTH), "8FVC9G8F+6X");
+        assert_eq!(zurich.to_plus_code(11), "8FVC9G8F+6XQ");
+        assert_eq!(zurich.to_plus_code(8), "8FVC9G8F+");
+        assert_eq!(zurich.to_plus_code(4), "8FVC0000+");
+        assert_eq!(zurich.to_plus_code(3), "8FVC0000+");
+        assert_eq!(zurich.to_plus_code(0), "8F000000+");
+        assert_eq!(zurich.to_plus_code(20).len(), MAX_PLUS_CODE_LENGTH + 1);
+
+        // Examples of the reference implementation's test data
+        assert_eq!(coord(8.0, 47.0).to_plus_code(10), "8FVC2222+22");
+        assert_eq!(coord(174.785_937_5, -41.273_062_5).to_plus_code(10), "4VCPPQGP+Q9");
+        assert_eq!(coord(2.775, 20.375).to_plus_code(6), "7FG49Q00+");
+        assert_eq!(coord(2.782_234_375, 20.370_112_5).to_plus_code(11), "7FG49QCJ+2VX");
+
+        // Poles and the dateline
+        assert_eq!(coord(1.0, 90.0).to_plus_code(4), "CFX30000+");
+        assert_eq!(coord(1.0, 90.0).to_plus_code(10), "CFX3X2X2+X2");
+        assert_eq!(coord(180.0, 1.0).to_plus_code(4), "62H20000+");
+        assert_eq!(coord(-180.0, -90.0).to_plus_code(4), "22220000+");
+    }
+
+    #[test]
+    fn from_plus_code() -> Result<(), InvalidPlusCode> {
+        assert!(GeoCoord::from_plus_code("8FVC2222+22")?.approx_eq(&coord(8.000_062_5, 47.000_062_5), 1e-9));
+        assert!(GeoCoord::from_plus_code("8fvc2222+22")?.approx_eq(&coord(8.000_062_5, 47.000_062_5), 1e-9));
+        assert!(GeoCoord::from_plus_code("7FG49Q00+")?.approx_eq(&coord(2.775, 20.375), 1e-9));
+        assert!(GeoCoord::from_plus_code("62G20000+")?.approx_eq(&coord(-179.5, 0.5), 1e-9));
+        assert!(GeoCoord::from_plus_code("8FVC9G8F+6XQ")?.approx_eq(&coord(8.524_984_375, 47.365_587_5), 1e-9));
+
+        let north_pole = GeoCoord::from_plus_code("CFX30000+")?;
+        assert!(north_pole.approx_eq(&coord(1.5, 89.5), 1e-9));
+
+        for invalid in &["", "8FVC2222", "8FVC2222+2", "8FVC+2222", "8FVC222+22", "8FV00000+", "8FVC0000+22", "8F0C0000+", "8FVC2222+22+", "8FVC2222+22AB", "8FVC2222+2222222222", "X2220000+", "2X220000+"] {
+            assert_eq!(GeoCoord::from_plus_code(invalid), Err(InvalidPlusCode), "{}", invalid);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_short_plus_code() -> Result<(), InvalidPlusCode> {
+        let zurich = coord(8.524_997, 47.365_590);
+        let full = GeoCoord::from_plus_code("8FVC9G8F+6X")?;
+
+        assert_eq!(GeoCoord::from_short_plus_code("9G8F+6X", &coord(8.5, 47.4))?, full);
+        assert_eq!(GeoCoord::from_short_plus_code("8F+6X", &zurich)?, full);
+        assert_eq!(GeoCoord::from_short_plus_code("8FVC9G8F+6X", &coord(-100.0, -40.0))?, full);
+
+        // The nearest match is in the neighbouring area of the reference
+        let recovered = GeoCoord::from_short_plus_code("CJ+2VX", &coord(2.8, 20.3))?;
+        assert!(recovered.approx_eq(&coord(2.782_234_375, 20.320_112_5), 1e-9));
+        let recovered = GeoCoord::from_short_plus_code("CJ+2VX", &coord(2.8, 20.349))?;
+        assert!(recovered.approx_eq(&coord(2.782_234_375, 20.370_112_5), 1e-9));
+
+        let across_dateline = GeoCoord::from_short_plus_code("2222+22", &coord(179.9, 1.0))?;
+        assert!(across_dateline.lon() < -179.0);
+
+        assert_eq!(GeoCoord::from_short_plus_code("9G8F6X", &zurich), Err(InvalidPlusCode));
+        assert_eq!(GeoCoord::from_short_plus_code("G8F+6X", &zurich), Err(InvalidPlusCode));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), InvalidPlusCode> {
+        for &(lon, lat) in &[(2.2945, 48.858222), (-74.006, 40.7128), (151.2093, -33.8688), (-179.99, -89.99), (179.99, 89.99)] {
+            let original = coord(lon, lat);
+
+            for &length in &[4, 10, 11, 15] {
+                let code = original.to_plus_code(length);
+                let center = GeoCoord::from_plus_code(&code)?;
+
+                assert_eq!(center.to_plus_code(length), code);
+                assert!(center.approx_eq(&original, 400.0 / 20f64.powi(length.min(10) as i32 / 2)), "{}", code);
+            }
+        }
+
+        Ok(())
+    }
+}
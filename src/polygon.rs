@@ -0,0 +1,137 @@
+use crate::coord::GeoCoord;
+
+use alloc::vec::Vec;
+
+/// Polygon made of an outer ring and any number of holes.
+///
+/// Rings are sequences of vertices and may be given closed or open. Consecutive vertices are
+/// joined by the shorter way around the globe, so edges may cross the dateline. Rings
+/// enclosing a pole are not supported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    exterior: Vec<GeoCoord>,
+    interiors: Vec<Vec<GeoCoord>>,
+}
+
+impl Polygon {
+    pub fn new(exterior: Vec<GeoCoord>, interiors: Vec<Vec<GeoCoord>>) -> Polygon {
+        Polygon { exterior, interiors }
+    }
+
+    pub fn exterior(&self) -> &[GeoCoord] {
+        &self.exterior
+    }
+
+    pub fn interiors(&self) -> &[Vec<GeoCoord>] {
+        &self.interiors
+    }
+
+    /// Whether `coord` lies inside the outer ring and outside of all holes.
+    ///
+    /// Coordinates exactly on an edge may be reported either way.
+    pub fn contains_coord(&self, coord: &GeoCoord) -> bool {
+        ring_contains(&self.exterior, coord) && !self.interiors.iter().any(|hole| ring_contains(hole, coord))
+    }
+}
+
+/// Even-odd ray casting with the ring unwrapped into a continuous longitude frame starting at
+/// its first vertex. The coordinate is tested at every longitude the unwrapped ring may reach.
+fn ring_contains(ring: &[GeoCoord], coord: &GeoCoord) -> bool {
+    if ring.len() < 3 {
+        return false;
+    }
+
+    let vertices = unwrap_ring(ring);
+
+    [-360.0, 0.0, 360.0]
+        .iter()
+        .any(|shift| crossings_odd(&vertices, coord.lon() + shift, coord.lat()))
+}
+
+fn unwrap_ring(ring: &[GeoCoord]) -> Vec<(f64, f64)> {
+    let mut vertices = Vec::with_capacity(ring.len());
+    let mut lon = ring[0].lon();
+
+    vertices.push((lon, ring[0].lat()));
+
+    for pair in ring.windows(2) {
+        let mut step = pair[1].lon() - pair[0].lon();
+
+        if step > 180.0 {
+            step -= 360.0;
+        } else if step < -180.0 {
+            step += 360.0;
+        }
+
+        lon += step;
+        vertices.push((lon, pair[1].lat()));
+    }
+
+    vertices
+}
+
+fn crossings_odd(vertices: &[(f64, f64)], lon: f64, lat: f64) -> bool {
+    let mut inside = false;
+    let mut prev = vertices[vertices.len() - 1];
+
+    for &vertex in vertices {
+        let ((lon_a, lat_a), (lon_b, lat_b)) = (prev, vertex);
+
+        if (lat_a > lat) != (lat_b > lat) {
+            let crossing_lon = lon_a + (lat - lat_a) / (lat_b - lat_a) * (lon_b - lon_a);
+
+            if lon < crossing_lon {
+                inside = !inside;
+            }
+        }
+
+        prev = vertex;
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod polygon_tests {
+    use super::*;
+    use crate::coord::InvalidGeoCoord;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    fn ring(points: &[(f64, f64)]) -> Vec<GeoCoord> {
+        points.iter().map(|&(lon, lat)| coord(lon, lat)).collect()
+    }
+
+    #[test]
+    fn contains_coord_with_hole() -> Result<(), InvalidGeoCoord> {
+        let exterior = ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]);
+        let hole = ring(&[(4.0, 4.0), (4.0, 6.0), (6.0, 6.0), (6.0, 4.0)]);
+        let polygon = Polygon::new(exterior, vec![hole]);
+
+        assert!(polygon.contains_coord(&GeoCoord::from_degrees(2.0, 2.0)?));
+        assert!(polygon.contains_coord(&GeoCoord::from_degrees(8.0, 5.0)?));
+        assert!(!polygon.contains_coord(&GeoCoord::from_degrees(5.0, 5.0)?));
+        assert!(!polygon.contains_coord(&GeoCoord::from_degrees(15.0, 5.0)?));
+        assert!(!polygon.contains_coord(&GeoCoord::from_degrees(5.0, -1.0)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn contains_coord_across_dateline() -> Result<(), InvalidGeoCoord> {
+        let exterior = ring(&[(170.0, -10.0), (-170.0, -10.0), (-170.0, 10.0), (170.0, 10.0)]);
+        let polygon = Polygon::new(exterior, Vec::new());
+
+        assert!(polygon.contains_coord(&GeoCoord::from_degrees(180.0, 0.0)?));
+        assert!(polygon.contains_coord(&GeoCoord::from_degrees(-180.0, 0.0)?));
+        assert!(polygon.contains_coord(&GeoCoord::from_degrees(175.0, 5.0)?));
+        assert!(polygon.contains_coord(&GeoCoord::from_degrees(-175.0, -5.0)?));
+        assert!(!polygon.contains_coord(&GeoCoord::from_degrees(0.0, 0.0)?));
+        assert!(!polygon.contains_coord(&GeoCoord::from_degrees(160.0, 0.0)?));
+        assert!(!polygon.contains_coord(&GeoCoord::from_degrees(-160.0, 0.0)?));
+
+        Ok(())
+    }
+}
@@ -1,11 +1,22 @@
 use bitflags::bitflags;
 
-use crate::coord::GeoCoord;
+use crate::coord::{wrap_lon, GeoCoord, EARTH_MEAN_RADIUS};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+use crate::mercator::MAX_MERCATOR_LATITUDE;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "RawGeoRect"))]
 pub struct GeoRect {
     top_left: GeoCoord,
     bottom_right: GeoCoord,
@@ -20,6 +31,65 @@ impl GeoRect {
         }
     }
 
+    /// Rectangle covering the whole globe.
+    pub fn world() -> GeoRect {
+        GeoRect::from_bbox(-180.0, -90.0, 180.0, 90.0).unwrap()
+    }
+
+    /// Rectangle covering the area representable in Web Mercator, i.e. the whole globe up to
+    /// `MAX_MERCATOR_LATITUDE`.
+    pub fn web_mercator_world() -> GeoRect {
+        GeoRect::from_bbox(-180.0, -MAX_MERCATOR_LATITUDE, 180.0, MAX_MERCATOR_LATITUDE).unwrap()
+    }
+
+    /// Rectangle from a bounding box in (west, south, east, north) order.
+    ///
+    /// A `min_lon` greater than `max_lon` results in a rectangle crossing the dateline.
+    pub fn from_bbox(min_lon: f64, min_lat: f64, max_lon: f64, max_lat: f64) -> Result<GeoRect, InvalidGeoRect> {
+        let top_left = GeoCoord::from_degrees(min_lon, max_lat).map_err(|_| InvalidGeoRect)?;
+        let bottom_right = GeoCoord::from_degrees(max_lon, min_lat).map_err(|_| InvalidGeoRect)?;
+
+        GeoRect::new(top_left, bottom_right)
+    }
+
+    /// Minimal bounding rectangle of the given coordinates, or `None` if there are none.
+    ///
+    /// The longitude range is the complement of the widest gap between the coordinates, so
+    /// points clustered around the dateline result in a crossing rectangle.
+    pub fn from_coords<I: IntoIterator<Item = GeoCoord>>(coords: I) -> Option<GeoRect> {
+        let mut lons = Vec::new();
+        let mut top = f64::MIN;
+        let mut bottom = f64::MAX;
+
+        for coord in coords {
+            lons.push(coord.lon());
+            top = top.max(coord.lat());
+            bottom = bottom.min(coord.lat());
+        }
+
+        if lons.is_empty() {
+            return None;
+        }
+
+        lons.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let (mut west, mut east) = (lons[0], lons[lons.len() - 1]);
+        let mut widest_gap = lons[0] + 360.0 - lons[lons.len() - 1];
+
+        for pair in lons.windows(2) {
+            if pair[1] - pair[0] > widest_gap {
+                widest_gap = pair[1] - pair[0];
+                west = pair[1];
+                east = pair[0];
+            }
+        }
+
+        Some(GeoRect::new(
+            GeoCoord::from_degrees(west, top).unwrap(),
+            GeoCoord::from_degrees(east, bottom).unwrap(),
+        ).unwrap())
+    }
+
     pub fn top_left(&self) -> GeoCoord {
         self.top_left
     }
@@ -28,6 +98,24 @@ impl GeoRect {
         self.bottom_right
     }
 
+    pub fn top_right(&self) -> GeoCoord {
+        GeoCoord::from_degrees(self.bottom_right.lon(), self.top_left.lat()).unwrap()
+    }
+
+    pub fn bottom_left(&self) -> GeoCoord {
+        GeoCoord::from_degrees(self.top_left.lon(), self.bottom_right.lat()).unwrap()
+    }
+
+    /// The four corners clockwise, starting at the top left.
+    pub fn corners(&self) -> [GeoCoord; 4] {
+        [self.top_left, self.top_right(), self.bottom_right, self.bottom_left()]
+    }
+
+    /// Bounding box in (west, south, east, north) order.
+    pub fn to_bbox(&self) -> (f64, f64, f64, f64) {
+        (self.top_left.lon(), self.bottom_right.lat(), self.bottom_right.lon(), self.top_left.lat())
+    }
+
     pub fn center(&self) -> GeoCoord {
         let lat = (self.top_left.lat() + self.bottom_right.lat()) / 2.0;
 
@@ -47,6 +135,281 @@ impl GeoRect {
         self.top_left.lon() > self.bottom_right.lon()
     }
 
+    /// Longitude span in degrees, measured eastward from the western edge, so a rectangle from
+    /// 170° to -170° is 20° wide.
+    pub fn width_degrees(&self) -> f64 {
+        if self.crosses_dateline() {
+            360.0 - (self.top_left.lon() - self.bottom_right.lon())
+        } else {
+            self.bottom_right.lon() - self.top_left.lon()
+        }
+    }
+
+    /// Latitude span in degrees.
+    pub fn height_degrees(&self) -> f64 {
+        self.top_left.lat() - self.bottom_right.lat()
+    }
+
+    /// Surface area in square meters on a sphere of `EARTH_MEAN_RADIUS`.
+    pub fn area_m2(&self) -> f64 {
+        let lat_band = self.top_left.lat_rad().sin() - self.bottom_right.lat_rad().sin();
+
+        EARTH_MEAN_RADIUS * EARTH_MEAN_RADIUS * self.width_degrees().to_radians() * lat_band
+    }
+
+    /// Ratio of the area shared by `self` and `other` to the area covered by either, from 0.0
+    /// for disjoint rectangles to 1.0 for identical ones.
+    ///
+    /// Both longitude ranges shared by rectangles crossing the globe in opposite directions are
+    /// taken into account. Degenerate rectangles without area only score 1.0 against themselves.
+    pub fn iou(&self, other: &GeoRect) -> f64 {
+        let top = self.top_left.lat().min(other.top_left.lat());
+        let bottom = self.bottom_right.lat().max(other.bottom_right.lat());
+
+        let intersection = if top > bottom {
+            let lat_band = top.to_radians().sin() - bottom.to_radians().sin();
+            EARTH_MEAN_RADIUS * EARTH_MEAN_RADIUS * self.lon_overlap(other).to_radians() * lat_band
+        } else {
+            0.0
+        };
+
+        let union = self.area_m2() + other.area_m2() - intersection;
+
+        if union > 0.0 {
+            (intersection / union).min(1.0)
+        } else if self == other {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Smallest rectangle containing both `self` and `other`.
+    ///
+    /// The longitude range may cross the dateline if that results in a narrower rectangle. If
+    /// both choices are equally wide (e.g. for two rectangles on opposite sides of the globe),
+    /// the one starting at the western edge of `self` is returned.
+    pub fn union(&self, other: &GeoRect) -> GeoRect {
+        let top = self.top_left.lat().max(other.top_left.lat());
+        let bottom = self.bottom_right.lat().min(other.bottom_right.lat());
+
+        let west_a = self.top_left.lon();
+        let west_b = other.top_left.lon();
+        let span_a = self.width_degrees();
+        let span_b = other.width_degrees();
+
+        let from_a = ((west_b - west_a).rem_euclid(360.0) + span_b).max(span_a);
+        let from_b = ((west_a - west_b).rem_euclid(360.0) + span_a).max(span_b);
+
+        let (west, span) = if from_a <= from_b { (west_a, from_a) } else { (west_b, from_b) };
+
+        let (west, east) = if span >= 360.0 {
+            (-180.0, 180.0)
+        } else if west + span > 180.0 {
+            (west, west + span - 360.0)
+        } else {
+            (west, west + span)
+        };
+
+        GeoRect::new(
+            GeoCoord::from_degrees(west, top).unwrap(),
+            GeoCoord::from_degrees(east, bottom).unwrap(),
+        ).unwrap()
+    }
+
+    /// Overlapping part of `self` and `other`, or `None` if they are disjoint.
+    ///
+    /// Two rectangles crossing the globe in opposite directions may overlap in two separate
+    /// longitude ranges; in that case the range containing the western edge of `self` is
+    /// returned, or failing that, the one containing the western edge of `other`. Rectangles
+    /// only meeting at a pole intersect in the pole itself.
+    pub fn intersection(&self, other: &GeoRect) -> Option<GeoRect> {
+        let top = self.top_left.lat().min(other.top_left.lat());
+        let bottom = self.bottom_right.lat().max(other.bottom_right.lat());
+
+        if top < bottom {
+            return None;
+        }
+
+        let west = if other.contains_lon(self.top_left.lon()) {
+            self.top_left.lon()
+        } else if self.contains_lon(other.top_left.lon()) {
+            other.top_left.lon()
+        } else if top == 90.0 || bottom == -90.0 {
+            let pole = GeoCoord::from_degrees(self.top_left.lon(), if top == 90.0 { top } else { bottom }).unwrap();
+            return Some(GeoRect { top_left: pole, bottom_right: pole });
+        } else {
+            return None;
+        };
+
+        let remaining = |rect: &GeoRect| rect.width_degrees() - (west - rect.top_left.lon()).rem_euclid(360.0);
+        let span = remaining(self).min(remaining(other)).max(0.0);
+
+        let east = if west + span > 180.0 { west + span - 360.0 } else { west + span };
+
+        Some(GeoRect::new(
+            GeoCoord::from_degrees(west, top).unwrap(),
+            GeoCoord::from_degrees(east, bottom).unwrap(),
+        ).unwrap())
+    }
+
+    /// Grows the rectangle outward by margins given in degrees, treating negative and NaN margins
+    /// as zero.
+    ///
+    /// Latitudes are clamped at the poles, while longitudes wrap around the dateline, which can
+    /// turn a normal rectangle into a crossing one. Spanning the whole globe results in a
+    /// -180..180 longitude range.
+    pub fn expanded(&self, lon_deg: f64, lat_deg: f64) -> GeoRect {
+        // `f64::max` returns the other operand for NaN
+        let lon_deg = lon_deg.max(0.0);
+        let lat_deg = lat_deg.max(0.0);
+
+        let top = (self.top_left.lat() + lat_deg).min(90.0);
+        let bottom = (self.bottom_right.lat() - lat_deg).max(-90.0);
+
+        let (west, east) = if self.width_degrees() + 2.0 * lon_deg >= 360.0 {
+            (-180.0, 180.0)
+        } else {
+            (wrap_lon(self.top_left.lon() - lon_deg), wrap_lon(self.bottom_right.lon() + lon_deg))
+        };
+
+        GeoRect::new(
+            GeoCoord::from_degrees(west, top).unwrap(),
+            GeoCoord::from_degrees(east, bottom).unwrap(),
+        ).unwrap()
+    }
+
+    /// Closed ring of the corners in counter-clockwise order, starting and ending at the bottom
+    /// left, as expected for exterior rings by GeoJSON.
+    ///
+    /// The ring of a rectangle crossing the dateline wraps from 180° to -180°; use
+    /// `to_polygon_rings` for consumers that can't handle that.
+    pub fn to_polygon(&self) -> Vec<GeoCoord> {
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+
+        vec![bottom_left, bottom_right, top_right, top_left, bottom_left]
+    }
+
+    /// Closed counter-clockwise rings of the pieces returned by `split_at_dateline`.
+    pub fn to_polygon_rings(&self) -> Vec<Vec<GeoCoord>> {
+        self.split_at_dateline().iter().map(GeoRect::to_polygon).collect()
+    }
+
+    /// The rectangle itself if it doesn't cross the dateline, or its two pieces ending at 180°
+    /// and starting at -180° otherwise.
+    pub fn split_at_dateline(&self) -> Vec<GeoRect> {
+        if !self.crosses_dateline() {
+            return vec![self.clone()];
+        }
+
+        vec![
+            GeoRect {
+                top_left: self.top_left,
+                bottom_right: GeoCoord::from_degrees(180.0, self.bottom_right.lat()).unwrap(),
+            },
+            GeoRect {
+                top_left: GeoCoord::from_degrees(-180.0, self.top_left.lat()).unwrap(),
+                bottom_right: self.bottom_right,
+            },
+        ]
+    }
+
+    /// Splits the rectangle into an even grid of `cols` × `rows` pieces, returned in row-major
+    /// order starting at the top left.
+    ///
+    /// Pieces of a rectangle crossing the dateline are split at it, so none of them cross it
+    /// unless a single piece spans it.
+    pub fn subdivide(&self, cols: usize, rows: usize) -> Vec<GeoRect> {
+        let west = self.top_left.lon();
+        let top = self.top_left.lat();
+        let lon_step = self.width_degrees() / cols as f64;
+        let lat_step = (top - self.bottom_right.lat()) / rows as f64;
+
+        let lon_at = |col: usize| {
+            if col == cols {
+                self.bottom_right.lon()
+            } else {
+                let lon = west + col as f64 * lon_step;
+                if col > 0 && lon >= 180.0 { lon - 360.0 } else { lon }
+            }
+        };
+
+        let lat_at = |row: usize| if row == rows { self.bottom_right.lat() } else { top - row as f64 * lat_step };
+
+        let mut pieces = Vec::with_capacity(cols * rows);
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let east = lon_at(col + 1);
+                let east = if east == -180.0 { 180.0 } else { east };
+
+                pieces.push(GeoRect::new(
+                    GeoCoord::from_degrees(lon_at(col), lat_at(row)).unwrap(),
+                    GeoCoord::from_degrees(east, lat_at(row + 1)).unwrap(),
+                ).unwrap());
+            }
+        }
+
+        pieces
+    }
+
+    /// Splits the rectangle at its center into the NW, NE, SW and SE quadrants, the latitude
+    /// split being the mean of the top and bottom latitudes.
+    pub fn quadrants(&self) -> [GeoRect; 4] {
+        self.quadrants_at((self.top_left.lat() + self.bottom_right.lat()) / 2.0)
+    }
+
+    /// Splits the rectangle into the NW, NE, SW and SE quadrants at the center of the rectangle
+    /// projected to Web Mercator, so the quadrants match the children of a map tile.
+    ///
+    /// Above the latitude limit of Web Mercator, the southern quadrants of a rectangle collapse
+    /// to its bottom edge, and likewise for the northern ones below it.
+    pub fn quadrants_mercator(&self) -> [GeoRect; 4] {
+        let (_, top) = self.top_left.to_web_mercator();
+        let (_, bottom) = self.bottom_right.to_web_mercator();
+
+        self.quadrants_at(GeoCoord::from_web_mercator(0.0, (top + bottom) / 2.0).unwrap().lat())
+    }
+
+    fn quadrants_at(&self, mid_lat: f64) -> [GeoRect; 4] {
+        let halves = self.subdivide(2, 1);
+        let (west, east) = (&halves[0], &halves[1]);
+
+        let top = self.top_left.lat();
+        let bottom = self.bottom_right.lat();
+        let mid_lat = mid_lat.max(bottom).min(top);
+
+        let quadrant = |half: &GeoRect, top: f64, bottom: f64| GeoRect::new(
+            GeoCoord::from_degrees(half.top_left.lon(), top).unwrap(),
+            GeoCoord::from_degrees(half.bottom_right.lon(), bottom).unwrap(),
+        ).unwrap();
+
+        [
+            quadrant(west, top, mid_lat),
+            quadrant(east, top, mid_lat),
+            quadrant(west, mid_lat, bottom),
+            quadrant(east, mid_lat, bottom),
+        ]
+    }
+
+    /// Total width in degrees of the longitude ranges shared by `self` and `other`.
+    fn lon_overlap(&self, other: &GeoRect) -> f64 {
+        let remaining = |rect: &GeoRect, west: f64| rect.width_degrees() - (west - rect.top_left.lon()).rem_euclid(360.0);
+        let overlap_from = |west: f64| remaining(self, west).min(remaining(other, west)).max(0.0);
+
+        let mut overlap = 0.0;
+
+        if other.contains_lon(self.top_left.lon()) {
+            overlap += overlap_from(self.top_left.lon());
+        }
+
+        if self.contains_lon(other.top_left.lon()) && other.top_left.lon() != self.top_left.lon() {
+            overlap += overlap_from(other.top_left.lon());
+        }
+
+        overlap
+    }
+
     fn contains_lon(&self, lon: f64) -> bool {
         if !self.crosses_dateline() {
             lon >= self.top_left.lon() && lon <= self.bottom_right.lon()
@@ -64,10 +427,8 @@ impl GeoRect {
     }
 
     pub fn contains_rect(&self, rect: &GeoRect) -> bool {
-        if !self.crosses_dateline() && rect.crosses_dateline() {
-            if self.top_left.lon() > -180.0 || self.bottom_right.lon() < 180.0 {
-                return false;
-            }
+        if !self.crosses_dateline() && rect.crosses_dateline() && (self.top_left.lon() > -180.0 || self.bottom_right.lon() < 180.0) {
+            return false;
         }
 
         self.contains_coord(&rect.top_left) && self.contains_coord(&rect.bottom_right)
@@ -85,6 +446,90 @@ impl GeoRect {
             self.contains_lon(rect.top_left.lon()) || self.contains_lon(rect.bottom_right.lon())
         }
     }
+
+    /// Whether `self` and `other` share part of an edge or a corner without overlapping in area.
+    ///
+    /// Edges on opposite sides of the dateline meet, as do rectangles reaching the same pole.
+    pub fn touches(&self, other: &GeoRect) -> bool {
+        let top = self.top_left.lat().min(other.top_left.lat());
+        let bottom = self.bottom_right.lat().max(other.bottom_right.lat());
+
+        if top < bottom {
+            return false;
+        }
+
+        let contains = |rect: &GeoRect, lon: f64| rect.contains_lon(lon) || (lon.abs() == 180.0 && rect.contains_lon(-lon));
+        let meets_lon = contains(self, other.top_left.lon()) || contains(self, other.bottom_right.lon())
+            || contains(other, self.top_left.lon()) || contains(other, self.bottom_right.lon());
+        let meets_at_pole = top == 90.0 || bottom == -90.0;
+
+        if !meets_lon && !meets_at_pole {
+            return false;
+        }
+
+        top == bottom || self.lon_overlap(other) == 0.0
+    }
+}
+
+impl FromStr for GeoRect {
+    type Err = InvalidGeoRect;
+
+    /// Parses a bounding box given as "west,south,east,north".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = [0.0; 4];
+        let mut parts = s.trim().split(',');
+
+        for value in values.iter_mut() {
+            let part = parts.next().ok_or(InvalidGeoRect)?;
+            *value = part.trim().parse().map_err(|_| InvalidGeoRect)?;
+        }
+
+        if parts.next().is_some() {
+            return Err(InvalidGeoRect);
+        }
+
+        GeoRect::from_bbox(values[0], values[1], values[2], values[3])
+    }
+}
+
+/// Fails for rectangles crossing the dateline, which `geo_types::Rect` cannot represent.
+#[cfg(feature = "geo-types")]
+impl core::convert::TryFrom<GeoRect> for geo_types::Rect<f64> {
+    type Error = InvalidGeoRect;
+
+    fn try_from(rect: GeoRect) -> Result<Self, Self::Error> {
+        if rect.crosses_dateline() {
+            return Err(InvalidGeoRect);
+        }
+
+        Ok(geo_types::Rect::new(rect.bottom_left(), rect.top_right()))
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl core::convert::TryFrom<geo_types::Rect<f64>> for GeoRect {
+    type Error = InvalidGeoRect;
+
+    fn try_from(rect: geo_types::Rect<f64>) -> Result<Self, Self::Error> {
+        GeoRect::from_bbox(rect.min().x, rect.min().y, rect.max().x, rect.max().y)
+    }
+}
+
+/// Unvalidated `GeoRect` used for deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawGeoRect {
+    top_left: GeoCoord,
+    bottom_right: GeoCoord,
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<RawGeoRect> for GeoRect {
+    type Error = InvalidGeoRect;
+
+    fn try_from(raw: RawGeoRect) -> Result<Self, Self::Error> {
+        GeoRect::new(raw.top_left, raw.bottom_right)
+    }
 }
 
 bitflags! {
@@ -96,6 +541,31 @@ bitflags! {
     }
 }
 
+impl Edge {
+    /// The edge facing the other way, swapping left with right and top with bottom.
+    pub fn opposite(&self) -> Edge {
+        let mut opposite = Edge::empty();
+
+        opposite.set(Edge::LEFT, self.contains(Edge::RIGHT));
+        opposite.set(Edge::RIGHT, self.contains(Edge::LEFT));
+        opposite.set(Edge::TOP, self.contains(Edge::BOTTOM));
+        opposite.set(Edge::BOTTOM, self.contains(Edge::TOP));
+
+        opposite
+    }
+
+    /// Column and row delta of the tile across the edge or corner, y growing downwards.
+    ///
+    /// Opposing flags cancel out, and wrapping across the dateline or the poles isn't
+    /// accounted for.
+    pub fn offset(&self) -> (i32, i32) {
+        let dx = self.contains(Edge::RIGHT) as i32 - self.contains(Edge::LEFT) as i32;
+        let dy = self.contains(Edge::BOTTOM) as i32 - self.contains(Edge::TOP) as i32;
+
+        (dx, dy)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct InvalidGeoRect;
 
@@ -105,6 +575,7 @@ impl fmt::Display for InvalidGeoRect {
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for InvalidGeoRect {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         None
@@ -135,6 +606,80 @@ mod geo_rect_tests {
         assert!(invalid_rect.is_err());
     }
 
+    #[test]
+    fn bbox() -> Result<(), InvalidGeoRect> {
+        let normal_rect = GeoRect::from_bbox(-10.0, -20.0, 10.0, 20.0)?;
+        assert_eq!(normal_rect, rect((-10.0, 20.0), (10.0, -20.0)));
+        assert_eq!(normal_rect.to_bbox(), (-10.0, -20.0, 10.0, 20.0));
+
+        let crossing_rect = GeoRect::from_bbox(170.0, -20.0, -170.0, 20.0)?;
+        assert!(crossing_rect.crosses_dateline());
+        assert_eq!(crossing_rect, rect((170.0, 20.0), (-170.0, -20.0)));
+        assert_eq!(crossing_rect.to_bbox(), (170.0, -20.0, -170.0, 20.0));
+
+        assert!(GeoRect::from_bbox(-10.0, 20.0, 10.0, -20.0).is_err());
+        assert!(GeoRect::from_bbox(-190.0, -20.0, 10.0, 20.0).is_err());
+        assert!(GeoRect::from_bbox(-10.0, -20.0, 10.0, 95.0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_str() -> Result<(), InvalidGeoRect> {
+        assert_eq!("-10,-20,10,20".parse::<GeoRect>()?, rect((-10.0, 20.0), (10.0, -20.0)));
+        assert_eq!(" 170.5, -20 , -170.5,20 ".parse::<GeoRect>()?, rect((170.5, 20.0), (-170.5, -20.0)));
+
+        assert!("-10,-20,10".parse::<GeoRect>().is_err());
+        assert!("-10,-20,10,20,30".parse::<GeoRect>().is_err());
+        assert!("-10,-20,ten,20".parse::<GeoRect>().is_err());
+        assert!("-10,20,10,-20".parse::<GeoRect>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn world() {
+        let world = GeoRect::world();
+        assert_eq!(world, rect((-180.0, 90.0), (180.0, -90.0)));
+        assert!(!world.crosses_dateline());
+
+        for &(lon, lat) in &[(0.0, 0.0), (-180.0, 0.0), (180.0, 0.0), (0.0, 90.0), (0.0, -90.0), (-180.0, -90.0), (180.0, 90.0), (123.4, -56.7)] {
+            assert!(world.contains_coord(&coord(lon, lat)));
+        }
+
+        let mercator_world = GeoRect::web_mercator_world();
+        assert_eq!(mercator_world, rect((-180.0, MAX_MERCATOR_LATITUDE), (180.0, -MAX_MERCATOR_LATITUDE)));
+        assert!(world.contains_rect(&mercator_world));
+
+        for &(lon, lat) in &[(0.0, 0.0), (-180.0, MAX_MERCATOR_LATITUDE), (180.0, -MAX_MERCATOR_LATITUDE), (90.0, 85.0)] {
+            assert!(mercator_world.contains_coord(&coord(lon, lat)));
+        }
+
+        assert!(!mercator_world.contains_coord(&coord(0.0, 90.0)));
+        assert!(!mercator_world.contains_coord(&coord(0.0, -85.06)));
+    }
+
+    #[test]
+    fn from_coords() {
+        assert_eq!(GeoRect::from_coords(Vec::new()), None);
+
+        assert_eq!(GeoRect::from_coords(vec![coord(5.0, 10.0)]), Some(rect((5.0, 10.0), (5.0, 10.0))));
+
+        let coords = vec![coord(-10.0, 5.0), coord(10.0, -20.0), coord(0.0, 20.0)];
+        assert_eq!(GeoRect::from_coords(coords), Some(rect((-10.0, 20.0), (10.0, -20.0))));
+
+        let straddling = vec![coord(175.0, 5.0), coord(-178.0, -5.0), coord(179.0, 0.0), coord(-170.0, 2.0)];
+        let bbox = GeoRect::from_coords(straddling).unwrap();
+        assert!(bbox.crosses_dateline());
+        assert_eq!(bbox, rect((175.0, 5.0), (-170.0, -5.0)));
+
+        let polar = vec![coord(0.0, 89.0), coord(90.0, 89.5), coord(180.0, 90.0), coord(-90.0, 88.0)];
+        assert_eq!(GeoRect::from_coords(polar), Some(rect((-90.0, 90.0), (180.0, 88.0))));
+
+        let polar_cluster = vec![coord(-100.0, 88.0), coord(120.0, 89.0), coord(160.0, 88.5)];
+        assert_eq!(GeoRect::from_coords(polar_cluster), Some(rect((120.0, 89.0), (-100.0, 88.0))));
+    }
+
     #[test]
     fn center() {
         let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
@@ -150,6 +695,23 @@ mod geo_rect_tests {
         assert_eq!(crossing_rect.center(), coord(165.0, 0.0));
     }
 
+    #[test]
+    fn area_m2() {
+        let world = rect((-180.0, 90.0), (180.0, -90.0));
+        let sphere = 4.0 * std::f64::consts::PI * EARTH_MEAN_RADIUS * EARTH_MEAN_RADIUS;
+        assert!((world.area_m2() - sphere).abs() / sphere < 1e-12);
+
+        let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
+        let crossing_rect = rect((170.0, 20.0), (-170.0, -20.0));
+        assert!((normal_rect.area_m2() - crossing_rect.area_m2()).abs() < 1e-3);
+
+        let northern_hemisphere = rect((-180.0, 90.0), (180.0, 0.0));
+        assert!((northern_hemisphere.area_m2() - sphere / 2.0).abs() / sphere < 1e-12);
+
+        let flat_rect = rect((-10.0, 20.0), (10.0, 20.0));
+        assert_eq!(flat_rect.area_m2(), 0.0);
+    }
+
     #[test]
     fn crosses_dateline() {
         let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
@@ -159,6 +721,68 @@ mod geo_rect_tests {
         assert!(crossing_rect.crosses_dateline());
     }
 
+    #[test]
+    fn corners() {
+        let normal_rect = rect((-10.0, 20.0), (15.0, -20.0));
+        assert_eq!(normal_rect.corners(), [coord(-10.0, 20.0), coord(15.0, 20.0), coord(15.0, -20.0), coord(-10.0, -20.0)]);
+
+        let crossing_rect = GeoRect::from_bbox(170.0, -20.0, -170.0, 30.0).unwrap();
+        let (west, south, east, north) = crossing_rect.to_bbox();
+        assert_eq!(crossing_rect.top_right(), coord(east, north));
+        assert_eq!(crossing_rect.bottom_left(), coord(west, south));
+        assert_eq!(crossing_rect.corners(), [coord(west, north), coord(east, north), coord(east, south), coord(west, south)]);
+        assert!(crossing_rect.corners().iter().all(|corner| crossing_rect.contains_coord(corner)));
+    }
+
+    #[test]
+    fn to_polygon() {
+        // Twice the signed area of a ring, positive for counter-clockwise winding
+        fn signed_area(ring: &[GeoCoord]) -> f64 {
+            ring.windows(2).map(|pair| pair[0].lon() * pair[1].lat() - pair[1].lon() * pair[0].lat()).sum()
+        }
+
+        let normal_rect = rect((-10.0, 20.0), (15.0, -20.0));
+        let ring = normal_rect.to_polygon();
+        assert_eq!(ring, vec![coord(-10.0, -20.0), coord(15.0, -20.0), coord(15.0, 20.0), coord(-10.0, 20.0), coord(-10.0, -20.0)]);
+        assert_eq!(ring.first(), ring.last());
+        assert!(signed_area(&ring) > 0.0);
+        assert_eq!(normal_rect.to_polygon_rings(), vec![ring]);
+
+        let crossing_rect = rect((170.0, 20.0), (-170.0, -20.0));
+        let ring = crossing_rect.to_polygon();
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+
+        let rings = crossing_rect.to_polygon_rings();
+        assert_eq!(rings.len(), 2);
+        assert_eq!(rings[0][1], coord(180.0, -20.0));
+        assert_eq!(rings[1][0], coord(-180.0, -20.0));
+
+        for ring in &rings {
+            assert_eq!(ring.len(), 5);
+            assert_eq!(ring.first(), ring.last());
+            assert!(signed_area(ring) > 0.0);
+        }
+    }
+
+    #[test]
+    fn width_and_height_degrees() {
+        let normal_rect = rect((-10.0, 20.0), (15.0, -20.0));
+        assert_eq!(normal_rect.width_degrees(), 25.0);
+        assert_eq!(normal_rect.height_degrees(), 40.0);
+
+        let crossing_rect = rect((170.0, 30.0), (-170.0, 25.0));
+        assert_eq!(crossing_rect.width_degrees(), 20.0);
+        assert_eq!(crossing_rect.height_degrees(), 5.0);
+
+        assert_eq!(GeoRect::world().width_degrees(), 360.0);
+        assert_eq!(GeoRect::world().height_degrees(), 180.0);
+
+        let point_rect = rect((5.0, 10.0), (5.0, 10.0));
+        assert_eq!(point_rect.width_degrees(), 0.0);
+        assert_eq!(point_rect.height_degrees(), 0.0);
+    }
+
     #[test]
     fn contains_coord() {
         let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
@@ -195,6 +819,151 @@ mod geo_rect_tests {
         assert!(normal_rect_5.contains_rect(&crossing_rect_1));
     }
 
+    #[test]
+    fn union() {
+        let normal_rect_1 = rect((-10.0, 20.0), (10.0, -20.0));
+        assert_eq!(normal_rect_1.union(&normal_rect_1), normal_rect_1);
+
+        let normal_rect_2 = rect((0.0, 30.0), (20.0, 0.0));
+        assert_eq!(normal_rect_1.union(&normal_rect_2), rect((-10.0, 30.0), (20.0, -20.0)));
+        assert_eq!(normal_rect_2.union(&normal_rect_1), rect((-10.0, 30.0), (20.0, -20.0)));
+
+        let normal_rect_3 = rect((-5.0, 10.0), (5.0, -10.0));
+        assert_eq!(normal_rect_1.union(&normal_rect_3), normal_rect_1);
+
+        // Crossing the dateline is narrower than spanning the globe
+        let east_rect = rect((170.0, 10.0), (175.0, -10.0));
+        let west_rect = rect((-175.0, 5.0), (-170.0, -5.0));
+        assert_eq!(east_rect.union(&west_rect), rect((170.0, 10.0), (-170.0, -10.0)));
+        assert_eq!(west_rect.union(&east_rect), rect((170.0, 10.0), (-170.0, -10.0)));
+
+        let crossing_rect = rect((170.0, 20.0), (-170.0, -20.0));
+        assert_eq!(crossing_rect.union(&west_rect), crossing_rect);
+        assert_eq!(crossing_rect.union(&rect((-175.0, 20.0), (-160.0, -20.0))), rect((170.0, 20.0), (-160.0, -20.0)));
+
+        // Opposite sides of the globe: both directions are 200° wide, the one starting at self wins
+        assert_eq!(normal_rect_1.union(&crossing_rect), rect((-10.0, 20.0), (-170.0, -20.0)));
+        assert_eq!(crossing_rect.union(&normal_rect_1), rect((170.0, 20.0), (10.0, -20.0)));
+
+        let wide_rect_1 = rect((-180.0, 10.0), (20.0, -10.0));
+        let wide_rect_2 = rect((0.0, 10.0), (180.0, -10.0));
+        assert_eq!(wide_rect_1.union(&wide_rect_2), rect((-180.0, 10.0), (180.0, -10.0)));
+    }
+
+    #[test]
+    fn split_at_dateline() {
+        let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
+        assert_eq!(normal_rect.split_at_dateline(), vec![normal_rect.clone()]);
+
+        let crossing_rect = rect((170.0, 20.0), (-160.0, -20.0));
+        let pieces = crossing_rect.split_at_dateline();
+        assert_eq!(pieces, vec![rect((170.0, 20.0), (180.0, -20.0)), rect((-180.0, 20.0), (-160.0, -20.0))]);
+        assert!(pieces.iter().all(|piece| !piece.crosses_dateline()));
+
+        for lon in (-180..=180).step_by(5) {
+            for lat in (-30..=30).step_by(5) {
+                let coord = coord(lon as f64, lat as f64);
+                let in_pieces = pieces.iter().any(|piece| piece.contains_coord(&coord));
+                assert_eq!(in_pieces, crossing_rect.contains_coord(&coord), "{:?}", coord);
+            }
+        }
+    }
+
+    #[test]
+    fn subdivide() {
+        let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
+        assert_eq!(normal_rect.subdivide(2, 2), vec![
+            rect((-10.0, 20.0), (0.0, 0.0)),
+            rect((0.0, 20.0), (10.0, 0.0)),
+            rect((-10.0, 0.0), (0.0, -20.0)),
+            rect((0.0, 0.0), (10.0, -20.0)),
+        ]);
+
+        let crossing_rect = rect((170.0, 20.0), (-170.0, -20.0));
+        assert_eq!(crossing_rect.subdivide(2, 1), vec![rect((170.0, 20.0), (180.0, -20.0)), rect((-180.0, 20.0), (-170.0, -20.0))]);
+        assert_eq!(crossing_rect.subdivide(4, 1)[3], rect((-175.0, 20.0), (-170.0, -20.0)));
+
+        let wide_crossing_rect = rect((100.0, 20.0), (-140.0, -20.0));
+        assert_eq!(wide_crossing_rect.subdivide(3, 1)[1], rect((140.0, 20.0), (180.0, -20.0)));
+
+        assert_eq!(normal_rect.subdivide(1, 1), vec![normal_rect.clone()]);
+        assert!(normal_rect.subdivide(0, 3).is_empty());
+
+        for original in &[normal_rect, crossing_rect, wide_crossing_rect, GeoRect::world()] {
+            let pieces = original.subdivide(2, 2);
+            assert_eq!(pieces.len(), 4);
+
+            let union = pieces[1..].iter().fold(pieces[0].clone(), |acc, piece| acc.union(piece));
+            assert_eq!(&union, original);
+        }
+    }
+
+    #[test]
+    fn quadrants() {
+        for parent in &[rect((-10.0, 60.0), (10.0, 20.0)), rect((170.0, 20.0), (-150.0, -40.0)), GeoRect::world()] {
+            for [nw, ne, sw, se] in [parent.quadrants(), parent.quadrants_mercator()] {
+                assert_eq!(nw.top_left(), parent.top_left());
+                assert_eq!(se.bottom_right(), parent.bottom_right());
+
+                // Neighbouring quadrants share their edges
+                assert_eq!((nw.bottom_right().lon() - ne.top_left().lon()).rem_euclid(360.0), 0.0);
+                assert_eq!(nw.bottom_right().lat(), sw.top_left().lat());
+                assert_eq!(ne.bottom_right().lat(), se.top_left().lat());
+                assert_eq!((sw.top_left().lon(), sw.bottom_right().lon()), (nw.top_left().lon(), nw.bottom_right().lon()));
+                assert_eq!((se.top_left().lon(), se.bottom_right().lon()), (ne.top_left().lon(), ne.bottom_right().lon()));
+
+                let area = nw.area_m2() + ne.area_m2() + sw.area_m2() + se.area_m2();
+                assert!((area - parent.area_m2()).abs() / parent.area_m2() < 1e-9);
+
+                let union = nw.union(&ne).union(&sw).union(&se);
+                assert_eq!(&union, parent);
+            }
+        }
+
+        let [nw, ..] = rect((-10.0, 60.0), (10.0, 20.0)).quadrants();
+        assert_eq!(nw.bottom_right().lat(), 40.0);
+
+        let [nw, ..] = rect((-10.0, 60.0), (10.0, 20.0)).quadrants_mercator();
+        assert!(nw.bottom_right().lat() > 40.0);
+
+        // Beyond the Web Mercator limit the split is clamped into the rectangle
+        let polar_rect = GeoRect::from_bbox(0.0, 86.0, 10.0, 89.0).unwrap();
+        assert_eq!(polar_rect.quadrants()[2], rect((0.0, 87.5), (5.0, 86.0)));
+
+        let [nw, _, sw, _] = polar_rect.quadrants_mercator();
+        assert_eq!(nw, rect((0.0, 89.0), (5.0, 86.0)));
+        assert_eq!(sw.height_degrees(), 0.0);
+        assert_eq!(sw.bottom_right().lat(), 86.0);
+    }
+
+    #[test]
+    fn expanded() {
+        let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
+        assert_eq!(normal_rect.expanded(5.0, 10.0), rect((-15.0, 30.0), (15.0, -30.0)));
+        assert_eq!(normal_rect.expanded(0.0, 0.0), normal_rect);
+
+        let north_rect = rect((-10.0, 85.0), (10.0, 80.0));
+        assert_eq!(north_rect.expanded(0.0, 10.0), rect((-10.0, 90.0), (10.0, 70.0)));
+
+        let south_rect = rect((-10.0, -80.0), (10.0, -85.0));
+        assert_eq!(south_rect.expanded(0.0, 10.0), rect((-10.0, -70.0), (10.0, -90.0)));
+
+        let east_rect = rect((170.0, 10.0), (175.0, -10.0));
+        let expanded_rect = east_rect.expanded(10.0, 0.0);
+        assert!(expanded_rect.crosses_dateline());
+        assert_eq!(expanded_rect, rect((160.0, 10.0), (-175.0, -10.0)));
+
+        let crossing_rect = rect((170.0, 10.0), (-170.0, -10.0));
+        assert_eq!(crossing_rect.expanded(20.0, 0.0), rect((150.0, 10.0), (-150.0, -10.0)));
+
+        assert_eq!(normal_rect.expanded(170.0, 0.0), rect((-180.0, 20.0), (180.0, -20.0)));
+
+        assert_eq!(normal_rect.expanded(-5.0, -30.0), normal_rect);
+        assert_eq!(normal_rect.expanded(f64::NAN, f64::NAN), normal_rect);
+        assert_eq!(normal_rect.expanded(f64::INFINITY, f64::INFINITY), GeoRect::world());
+        assert_eq!(rect((-10.0, 5.0), (10.0, -5.0)).expanded(1.0, -20.0), rect((-11.0, 5.0), (11.0, -5.0)));
+    }
+
     #[test]
     fn intersects() {
         let normal_rect_1 = rect((-10.0, 20.0), (10.0, -20.0));
@@ -228,4 +997,162 @@ mod geo_rect_tests {
         let south_pole_rect_2 = rect((20.0, 20.0), (30.0, -90.0));
         assert!(south_pole_rect_1.intersects(&south_pole_rect_2));
     }
+
+    #[test]
+    fn touches() {
+        let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
+
+        let east_rect = rect((10.0, 10.0), (30.0, -30.0));
+        assert!(normal_rect.touches(&east_rect));
+        assert!(east_rect.touches(&normal_rect));
+
+        let north_rect = rect((-30.0, 40.0), (0.0, 20.0));
+        assert!(normal_rect.touches(&north_rect));
+
+        let corner_rect = rect((10.0, 40.0), (30.0, 20.0));
+        assert!(normal_rect.touches(&corner_rect));
+        assert!(corner_rect.touches(&normal_rect));
+
+        let overlapping_rect = rect((5.0, 20.0), (30.0, -20.0));
+        assert!(!normal_rect.touches(&overlapping_rect));
+        assert!(!normal_rect.touches(&normal_rect));
+
+        let disjoint_rect = rect((11.0, 20.0), (30.0, -20.0));
+        assert!(!normal_rect.touches(&disjoint_rect));
+
+        let west_of_dateline = rect((170.0, 10.0), (180.0, -10.0));
+        let east_of_dateline = rect((-180.0, 10.0), (-170.0, -10.0));
+        assert!(west_of_dateline.touches(&east_of_dateline));
+        assert!(east_of_dateline.touches(&west_of_dateline));
+
+        let crossing_rect = rect((170.0, 10.0), (-170.0, -10.0));
+        assert!(crossing_rect.touches(&rect((-170.0, 10.0), (-160.0, -10.0))));
+        assert!(!crossing_rect.touches(&east_of_dateline));
+
+        let north_pole_rect_1 = rect((-10.0, 90.0), (10.0, 80.0));
+        let north_pole_rect_2 = rect((20.0, 90.0), (30.0, 80.0));
+        assert!(north_pole_rect_1.touches(&north_pole_rect_2));
+    }
+
+    #[test]
+    fn iou() {
+        let normal_rect = rect((-10.0, 20.0), (10.0, -20.0));
+        assert_eq!(normal_rect.iou(&normal_rect), 1.0);
+
+        // Half of each rectangle is shared, so the intersection covers a third of the union
+        let shifted_rect = rect((0.0, 20.0), (20.0, -20.0));
+        assert!((normal_rect.iou(&shifted_rect) - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(normal_rect.iou(&shifted_rect), shifted_rect.iou(&normal_rect));
+
+        let inner_rect = rect((-10.0, 20.0), (0.0, -20.0));
+        assert!((normal_rect.iou(&inner_rect) - 0.5).abs() < 1e-12);
+
+        let disjoint_rect = rect((20.0, 20.0), (30.0, -20.0));
+        assert_eq!(normal_rect.iou(&disjoint_rect), 0.0);
+
+        let touching_rect = rect((10.0, 20.0), (30.0, -20.0));
+        assert_eq!(normal_rect.iou(&touching_rect), 0.0);
+
+        let crossing_rect = rect((170.0, 10.0), (-170.0, -10.0));
+        let east_of_dateline = rect((-180.0, 10.0), (-170.0, -10.0));
+        assert!((crossing_rect.iou(&east_of_dateline) - 0.5).abs() < 1e-12);
+
+        let point = rect((5.0, 5.0), (5.0, 5.0));
+        assert_eq!(point.iou(&point), 1.0);
+        assert_eq!(point.iou(&normal_rect), 0.0);
+    }
+
+    #[test]
+    fn intersection() {
+        let normal_rect_1 = rect((-10.0, 20.0), (10.0, -20.0));
+        assert_eq!(normal_rect_1.intersection(&normal_rect_1), Some(normal_rect_1.clone()));
+
+        let normal_rect_2 = rect((-5.0, 20.0), (5.0, -20.0));
+        assert_eq!(normal_rect_1.intersection(&normal_rect_2), Some(normal_rect_2.clone()));
+        assert_eq!(normal_rect_2.intersection(&normal_rect_1), Some(normal_rect_2.clone()));
+
+        let normal_rect_3 = rect((10.0, 25.0), (20.0, -15.0));
+        assert_eq!(normal_rect_1.intersection(&normal_rect_3), Some(rect((10.0, 20.0), (10.0, -15.0))));
+
+        // Overlaps in two separate ranges, the one at the western edge of self is returned
+        let crossing_rect_1 = rect((10.0, 20.0), (-10.0, -20.0));
+        assert_eq!(normal_rect_1.intersection(&crossing_rect_1), Some(rect((-10.0, 20.0), (-10.0, -20.0))));
+        assert_eq!(crossing_rect_1.intersection(&normal_rect_1), Some(rect((10.0, 20.0), (10.0, -20.0))));
+
+        let crossing_rect_2 = rect((5.0, 20.0), (-20.0, -20.0));
+        assert_eq!(crossing_rect_1.intersection(&crossing_rect_2), Some(rect((10.0, 20.0), (-20.0, -20.0))));
+
+        let normal_rect_4 = rect((-15.0, 15.0), (5.0, -15.0));
+        assert_eq!(crossing_rect_1.intersection(&normal_rect_4), Some(rect((-15.0, 15.0), (-10.0, -15.0))));
+
+        let crossing_rect_3 = rect((170.0, 10.0), (-170.0, -10.0));
+        assert_eq!(normal_rect_1.intersection(&crossing_rect_3), None);
+        assert_eq!(crossing_rect_1.intersection(&crossing_rect_3), Some(crossing_rect_3.clone()));
+
+        let normal_rect_5 = rect((-175.0, 40.0), (-170.0, -40.0));
+        assert_eq!(normal_rect_5.intersection(&crossing_rect_1), Some(rect((-175.0, 20.0), (-170.0, -20.0))));
+
+        let normal_rect_6 = rect((-10.0, 50.0), (10.0, 30.0));
+        assert_eq!(normal_rect_1.intersection(&normal_rect_6), None);
+
+        let north_pole_rect_1 = rect((-10.0, 90.0), (10.0, 80.0));
+        let north_pole_rect_2 = rect((20.0, 90.0), (30.0, 85.0));
+        assert_eq!(north_pole_rect_1.intersection(&north_pole_rect_2), Some(rect((0.0, 90.0), (0.0, 90.0))));
+
+        let south_pole_rect_1 = rect((-10.0, 20.0), (10.0, -90.0));
+        let south_pole_rect_2 = rect((20.0, 20.0), (30.0, -90.0));
+        assert_eq!(south_pole_rect_1.intersection(&south_pole_rect_2), Some(rect((0.0, -90.0), (0.0, -90.0))));
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types() {
+        use core::convert::TryFrom;
+
+        let normal = rect((-10.0, 20.0), (30.0, -5.0));
+        let converted = geo_types::Rect::try_from(normal.clone()).unwrap();
+        assert_eq!(converted, geo_types::Rect::new((-10.0, -5.0), (30.0, 20.0)));
+        assert_eq!(GeoRect::try_from(converted), Ok(normal));
+
+        let crossing = rect((170.0, 10.0), (-170.0, -10.0));
+        assert_eq!(geo_types::Rect::try_from(crossing), Err(InvalidGeoRect));
+
+        assert_eq!(GeoRect::try_from(geo_types::Rect::new((0.0, 0.0), (200.0, 10.0))), Err(InvalidGeoRect));
+    }
+
+    #[test]
+    fn edge_opposite() {
+        assert_eq!(Edge::LEFT.opposite(), Edge::RIGHT);
+        assert_eq!(Edge::RIGHT.opposite(), Edge::LEFT);
+        assert_eq!(Edge::TOP.opposite(), Edge::BOTTOM);
+        assert_eq!(Edge::BOTTOM.opposite(), Edge::TOP);
+
+        assert_eq!((Edge::TOP | Edge::LEFT).opposite(), Edge::BOTTOM | Edge::RIGHT);
+        assert_eq!((Edge::TOP | Edge::RIGHT).opposite(), Edge::BOTTOM | Edge::LEFT);
+        assert_eq!((Edge::BOTTOM | Edge::LEFT).opposite(), Edge::TOP | Edge::RIGHT);
+        assert_eq!((Edge::BOTTOM | Edge::RIGHT).opposite(), Edge::TOP | Edge::LEFT);
+
+        assert_eq!(Edge::empty().opposite(), Edge::empty());
+        assert_eq!((Edge::LEFT | Edge::RIGHT).opposite(), Edge::LEFT | Edge::RIGHT);
+    }
+
+    #[test]
+    fn edge_offset() {
+        assert_eq!(Edge::LEFT.offset(), (-1, 0));
+        assert_eq!(Edge::RIGHT.offset(), (1, 0));
+        assert_eq!(Edge::TOP.offset(), (0, -1));
+        assert_eq!(Edge::BOTTOM.offset(), (0, 1));
+
+        assert_eq!((Edge::TOP | Edge::LEFT).offset(), (-1, -1));
+        assert_eq!((Edge::TOP | Edge::RIGHT).offset(), (1, -1));
+        assert_eq!((Edge::BOTTOM | Edge::LEFT).offset(), (-1, 1));
+        assert_eq!((Edge::BOTTOM | Edge::RIGHT).offset(), (1, 1));
+
+        assert_eq!(Edge::empty().offset(), (0, 0));
+
+        for edge in &[Edge::LEFT, Edge::TOP | Edge::RIGHT, Edge::BOTTOM | Edge::LEFT] {
+            let (dx, dy) = edge.offset();
+            assert_eq!(edge.opposite().offset(), (-dx, -dy));
+        }
+    }
 }
@@ -0,0 +1,22 @@
+/// xorshift64 pseudo-random generator, good enough to cover bit patterns and spread
+/// coordinates over the globe in property tests.
+pub(crate) struct XorShift64(u64);
+
+impl XorShift64 {
+    /// The seed must not be zero, which the generator never leaves.
+    pub(crate) fn new(seed: u64) -> XorShift64 {
+        XorShift64(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniformly distributed value in `[0, 1]`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        self.next_u64() as f64 / u64::MAX as f64
+    }
+}
@@ -0,0 +1,273 @@
+use crate::coord::{wrap_lon, GeoCoord, WGS84_FLATTENING, WGS84_SEMI_MAJOR_AXIS};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatExt;
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+/// Latitude band letters from 80°S northward, 8° each except for the 12° of `X`.
+pub(crate) const BANDS: &[u8; 20] = b"CDEFGHJKLMNPQRSTUVWX";
+
+/// Scale factor on the central meridian of each zone.
+const SCALE_FACTOR: f64 = 0.9996;
+
+const FALSE_EASTING: f64 = 500_000.0;
+const FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    North,
+    South,
+}
+
+/// Universal Transverse Mercator coordinate in meters.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UtmCoord {
+    pub zone: u8,
+    pub hemisphere: Hemisphere,
+    pub easting: f64,
+    pub northing: f64,
+}
+
+impl GeoCoord {
+    /// UTM zone number and latitude band letter of the coordinate, taking the exceptions around
+    /// southwestern Norway and Svalbard into account.
+    ///
+    /// Beyond the UTM latitude range of 80°S to 84°N the letter is the one of the polar
+    /// stereographic (UPS) area instead: `A`/`B` in the south and `Y`/`Z` in the north, west and
+    /// east of the prime meridian respectively.
+    pub fn utm_zone(&self) -> (u8, char) {
+        let (lon, lat) = (self.lon(), self.lat());
+        let zone = utm_zone_number(lon, lat);
+
+        let band = if lat < -80.0 {
+            if lon < 0.0 { 'A' } else { 'B' }
+        } else if lat > 84.0 {
+            if lon < 0.0 { 'Y' } else { 'Z' }
+        } else {
+            BANDS[(((lat + 80.0) / 8.0) as usize).min(BANDS.len() - 1)] as char
+        };
+
+        (zone, band)
+    }
+
+    /// Projects the coordinate into its UTM zone.
+    ///
+    /// Uses the series expansion of Krüger to the fourth order of the third flattening, which is
+    /// accurate to well below a millimeter within a zone. Polar coordinates are projected into
+    /// their longitudinal zone as well, although they belong to UPS by convention.
+    pub fn to_utm(&self) -> UtmCoord {
+        let zone = utm_zone_number(self.lon(), self.lat());
+        self.to_utm_zone(zone)
+    }
+
+    /// Projects the coordinate into the given UTM zone instead of its own, e.g. to keep data
+    /// slightly crossing a zone boundary in a single zone.
+    pub fn to_utm_zone(&self, zone: u8) -> UtmCoord {
+        let n = third_flattening();
+        let alpha = [
+            n / 2.0 - 2.0 / 3.0 * n * n + 5.0 / 16.0 * n.powi(3) + 41.0 / 180.0 * n.powi(4),
+            13.0 / 48.0 * n * n - 3.0 / 5.0 * n.powi(3) + 557.0 / 1440.0 * n.powi(4),
+            61.0 / 240.0 * n.powi(3) - 103.0 / 140.0 * n.powi(4),
+            49561.0 / 161_280.0 * n.powi(4),
+        ];
+
+        let lat = self.lat_rad();
+        let dlon = wrap_lon(self.lon() - central_meridian(zone)).to_radians();
+
+        let c = 2.0 * n.sqrt() / (1.0 + n);
+        let t = (lat.sin().atanh() - c * (c * lat.sin()).atanh()).sinh();
+        let xi = t.atan2(dlon.cos());
+        let eta = (dlon.sin() / (1.0 + t * t).sqrt()).atanh();
+
+        let (mut easting, mut northing) = (eta, xi);
+
+        for (j, a) in alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            easting += a * (k * xi).cos() * (k * eta).sinh();
+            northing += a * (k * xi).sin() * (k * eta).cosh();
+        }
+
+        let k = SCALE_FACTOR * rectifying_radius(n);
+        let hemisphere = if self.lat() < 0.0 { Hemisphere::South } else { Hemisphere::North };
+        let false_northing = if hemisphere == Hemisphere::South { FALSE_NORTHING_SOUTH } else { 0.0 };
+
+        UtmCoord { zone, hemisphere, easting: FALSE_EASTING + k * easting, northing: false_northing + k * northing }
+    }
+
+    /// Unprojects a UTM coordinate, failing for zones outside of 1 to 60 or non-finite values.
+    pub fn from_utm(utm: &UtmCoord) -> Result<GeoCoord, InvalidUtmCoord> {
+        if !(1..=60).contains(&utm.zone) || !utm.easting.is_finite() || !utm.northing.is_finite() {
+            return Err(InvalidUtmCoord);
+        }
+
+        let n = third_flattening();
+        let beta = [
+            n / 2.0 - 2.0 / 3.0 * n * n + 37.0 / 96.0 * n.powi(3) - n.powi(4) / 360.0,
+            n * n / 48.0 + n.powi(3) / 15.0 - 437.0 / 1440.0 * n.powi(4),
+            17.0 / 480.0 * n.powi(3) - 37.0 / 840.0 * n.powi(4),
+            4397.0 / 161_280.0 * n.powi(4),
+        ];
+        let delta = [
+            2.0 * n - 2.0 / 3.0 * n * n - 2.0 * n.powi(3) + 116.0 / 45.0 * n.powi(4),
+            7.0 / 3.0 * n * n - 8.0 / 5.0 * n.powi(3) - 227.0 / 45.0 * n.powi(4),
+            56.0 / 15.0 * n.powi(3) - 136.0 / 35.0 * n.powi(4),
+            4279.0 / 630.0 * n.powi(4),
+        ];
+
+        let false_northing = if utm.hemisphere == Hemisphere::South { FALSE_NORTHING_SOUTH } else { 0.0 };
+        let k = SCALE_FACTOR * rectifying_radius(n);
+        let xi = (utm.northing - false_northing) / k;
+        let eta = (utm.easting - FALSE_EASTING) / k;
+
+        let (mut xi_prime, mut eta_prime) = (xi, eta);
+
+        for (j, b) in beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi_prime -= b * (k * xi).sin() * (k * eta).cosh();
+            eta_prime -= b * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        let chi = (xi_prime.sin() / eta_prime.cosh()).clamp(-1.0, 1.0).asin();
+        let mut lat = chi;
+
+        for (j, d) in delta.iter().enumerate() {
+            lat += d * (2.0 * (j + 1) as f64 * chi).sin();
+        }
+
+        let dlon = eta_prime.sinh().atan2(xi_prime.cos());
+        let lon = wrap_lon(central_meridian(utm.zone) + dlon.to_degrees());
+
+        GeoCoord::from_degrees(lon, lat.to_degrees().clamp(-90.0, 90.0)).map_err(|_| InvalidUtmCoord)
+    }
+}
+
+fn utm_zone_number(lon: f64, lat: f64) -> u8 {
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+        return 32;
+    }
+
+    if (72.0..=84.0).contains(&lat) && (0.0..42.0).contains(&lon) {
+        return match lon {
+            lon if lon < 9.0 => 31,
+            lon if lon < 21.0 => 33,
+            lon if lon < 33.0 => 35,
+            _ => 37,
+        };
+    }
+
+    (((lon + 180.0) / 6.0) as u8 + 1).min(60)
+}
+
+pub(crate) fn central_meridian(zone: u8) -> f64 {
+    f64::from(zone) * 6.0 - 183.0
+}
+
+fn third_flattening() -> f64 {
+    WGS84_FLATTENING / (2.0 - WGS84_FLATTENING)
+}
+
+/// Radius of the sphere with the same meridian length as the ellipsoid.
+fn rectifying_radius(n: f64) -> f64 {
+    WGS84_SEMI_MAJOR_AXIS / (1.0 + n) * (1.0 + n * n / 4.0 + n.powi(4) / 64.0)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidUtmCoord;
+
+impl fmt::Display for InvalidUtmCoord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid UTM coordinate given")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidUtmCoord {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod utm_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn utm_zone() {
+        assert_eq!(coord(7.5, 51.2).utm_zone(), (32, 'U'));
+        assert_eq!(coord(-74.0060, 40.7128).utm_zone(), (18, 'T'));
+        assert_eq!(coord(151.2093, -33.8688).utm_zone(), (56, 'H'));
+        assert_eq!(coord(-180.0, 0.0).utm_zone(), (1, 'N'));
+        assert_eq!(coord(180.0, -0.1).utm_zone(), (60, 'M'));
+        assert_eq!(coord(0.0, 83.9).utm_zone(), (31, 'X'));
+
+        // Southwestern Norway
+        assert_eq!(coord(5.3, 60.4).utm_zone(), (32, 'V'));
+        assert_eq!(coord(2.9, 60.4).utm_zone(), (31, 'V'));
+
+        // Svalbard
+        assert_eq!(coord(8.0, 78.0).utm_zone(), (31, 'X'));
+        assert_eq!(coord(15.6, 78.2).utm_zone(), (33, 'X'));
+        assert_eq!(coord(25.0, 78.0).utm_zone(), (35, 'X'));
+        assert_eq!(coord(40.0, 78.0).utm_zone(), (37, 'X'));
+        assert_eq!(coord(43.0, 78.0).utm_zone(), (38, 'X'));
+
+        // Polar areas
+        assert_eq!(coord(-10.0, -85.0).utm_zone().1, 'A');
+        assert_eq!(coord(10.0, -85.0).utm_zone().1, 'B');
+        assert_eq!(coord(-10.0, 85.0).utm_zone().1, 'Y');
+        assert_eq!(coord(10.0, 85.0).utm_zone().1, 'Z');
+    }
+
+    #[test]
+    fn to_utm() {
+        let utm = coord(7.5, 51.2).to_utm();
+        assert_eq!((utm.zone, utm.hemisphere), (32, Hemisphere::North));
+        assert!((utm.easting - 395_201.310).abs() < 0.01);
+        assert!((utm.northing - 5_673_135.241).abs() < 0.01);
+
+        let utm = coord(9.0, 0.0).to_utm();
+        assert_eq!((utm.easting, utm.northing), (FALSE_EASTING, 0.0));
+
+        let utm = coord(151.2093, -33.8688).to_utm();
+        assert_eq!((utm.zone, utm.hemisphere), (56, Hemisphere::South));
+        assert!(utm.northing > 6_000_000.0 && utm.northing < FALSE_NORTHING_SOUTH);
+        assert!(utm.easting < FALSE_EASTING);
+    }
+
+    #[test]
+    fn from_utm() -> Result<(), InvalidUtmCoord> {
+        let utm = UtmCoord { zone: 32, hemisphere: Hemisphere::North, easting: 340_000.0, northing: 5_710_000.0 };
+        let coord = GeoCoord::from_utm(&utm)?;
+        assert!((coord.lat() - 51.518_429_59).abs() < 1e-7);
+        assert!((coord.lon() - 6.693_877_49).abs() < 1e-7);
+
+        assert_eq!(GeoCoord::from_utm(&UtmCoord { zone: 0, ..utm }), Err(InvalidUtmCoord));
+        assert_eq!(GeoCoord::from_utm(&UtmCoord { zone: 61, ..utm }), Err(InvalidUtmCoord));
+        assert_eq!(GeoCoord::from_utm(&UtmCoord { easting: f64::NAN, ..utm }), Err(InvalidUtmCoord));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), InvalidUtmCoord> {
+        for &(lon, lat) in &[(7.5, 51.2), (2.2945, 48.858222), (-74.006, 40.7128), (151.2093, -33.8688), (-179.9, -79.9), (179.9, 83.9), (15.6, 78.2), (-70.0, -0.0001)] {
+            let original = coord(lon, lat);
+            let round_trip = GeoCoord::from_utm(&original.to_utm())?;
+
+            assert!(round_trip.approx_eq(&original, 1e-10), "{:?} != {:?}", round_trip, original);
+        }
+
+        // Projecting into a neighbouring zone stays accurate
+        let original = coord(12.1, 50.0);
+        let round_trip = GeoCoord::from_utm(&original.to_utm_zone(32))?;
+        assert!(round_trip.approx_eq(&original, 1e-9));
+
+        Ok(())
+    }
+}
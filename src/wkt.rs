@@ -0,0 +1,87 @@
+//! Well-Known Text output of the geometry types.
+//!
+//! WKT lists positions in x y order, i.e. longitude before latitude.
+
+use crate::coord::GeoCoord;
+use crate::line_string::LineString;
+use crate::rect::GeoRect;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl GeoCoord {
+    /// WKT Point of the coordinate in "lon lat" order, e.g. `POINT (2.2945 48.858222)`.
+    pub fn to_wkt(&self) -> String {
+        format!("POINT ({})", position(self))
+    }
+}
+
+impl GeoRect {
+    /// WKT Polygon of the rectangle in "lon lat" order, or a MultiPolygon of its two pieces if
+    /// it crosses the dateline.
+    pub fn to_wkt(&self) -> String {
+        let polygons: Vec<String> = self.to_polygon_rings().iter().map(|ring| format!("({})", positions(ring))).collect();
+
+        if let [polygon] = polygons.as_slice() {
+            format!("POLYGON {}", polygon)
+        } else {
+            format!("MULTIPOLYGON ({})", polygons.join(", "))
+        }
+    }
+}
+
+impl LineString {
+    /// WKT LineString of the line in "lon lat" order, or `LINESTRING EMPTY` without coordinates.
+    pub fn to_wkt(&self) -> String {
+        if self.coords().is_empty() {
+            String::from("LINESTRING EMPTY")
+        } else {
+            format!("LINESTRING {}", positions(self.coords()))
+        }
+    }
+}
+
+fn position(coord: &GeoCoord) -> String {
+    format!("{} {}", coord.lon(), coord.lat())
+}
+
+fn positions(coords: &[GeoCoord]) -> String {
+    format!("({})", coords.iter().map(position).collect::<Vec<_>>().join(", "))
+}
+
+#[cfg(test)]
+mod wkt_tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> GeoCoord {
+        GeoCoord::from_degrees(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn coord_to_wkt() {
+        // Longitude comes first
+        assert_eq!(coord(2.2945, 48.858222).to_wkt(), "POINT (2.2945 48.858222)");
+        assert_eq!(coord(-170.0, -0.5).to_wkt(), "POINT (-170 -0.5)");
+    }
+
+    #[test]
+    fn rect_to_wkt() {
+        let rect = GeoRect::from_bbox(-10.0, -5.0, 10.0, 5.0).unwrap();
+        assert_eq!(rect.to_wkt(), "POLYGON ((-10 -5, 10 -5, 10 5, -10 5, -10 -5))");
+
+        let crossing = GeoRect::from_bbox(170.0, -5.0, -170.0, 5.0).unwrap();
+        assert_eq!(
+            crossing.to_wkt(),
+            "MULTIPOLYGON (((170 -5, 180 -5, 180 5, 170 5, 170 -5)), ((-180 -5, -170 -5, -170 5, -180 5, -180 -5)))"
+        );
+    }
+
+    #[test]
+    fn line_string_to_wkt() {
+        let line = LineString::new(vec![coord(0.0, 0.0), coord(1.5, -2.25), coord(30.0, 60.0)]);
+        assert_eq!(line.to_wkt(), "LINESTRING (0 0, 1.5 -2.25, 30 60)");
+
+        assert_eq!(LineString::new(Vec::new()).to_wkt(), "LINESTRING EMPTY");
+    }
+}
@@ -0,0 +1,34 @@
+//! Smoke test of the core types without the standard library, run in CI with
+//! `cargo test --no-default-features --features libm --test no_std`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use rosm_geo::coord::{CompactGeoCoord, GeoCoord, TileCoord};
+use rosm_geo::mercator::{TileGrid, TileId};
+use rosm_geo::rect::GeoRect;
+
+#[test]
+fn core_types() {
+    let paris = GeoCoord::from_degrees(2.3522, 48.8566).unwrap();
+    let london = GeoCoord::from_degrees(-0.1278, 51.5074).unwrap();
+    assert!((paris.haversine_distance(&london) - 343_560.0).abs() < 500.0);
+    let compact = CompactGeoCoord::from(paris);
+    assert_eq!(CompactGeoCoord::from(GeoCoord::from(compact)), compact);
+    assert_eq!(paris.to_string().parse(), Ok(paris));
+
+    let rect = GeoRect::from_coords(Vec::from([paris, london])).unwrap();
+    assert!(rect.contains_coord(&rect.center()));
+
+    let grid = TileGrid::new(10, 256).unwrap();
+    let (tile_id, tile_coord) = grid.tile_id(&paris);
+    assert!(tile_coord.is_within(256));
+    assert!(grid.tile_bbox(tile_id).contains_coord(&paris));
+    assert_eq!(TileId::from_quadkey(&tile_id.to_quadkey()), Ok(tile_id));
+
+    assert_eq!(TileCoord::new(3, 4).magnitude(), 5.0);
+    assert_eq!(paris.to_geohash(5), "u09tv");
+}
@@ -0,0 +1,86 @@
+//! Round trips through binary serde formats, pinning the wire representation of the core
+//! types. Run with `cargo test --features serde --test serde`.
+#![cfg(feature = "serde")]
+
+use rosm_geo::coord::{CompactGeoCoord, GeoCoord};
+use rosm_geo::mercator::TileId;
+use rosm_geo::rect::GeoRect;
+
+fn coord(lon: f64, lat: f64) -> GeoCoord {
+    GeoCoord::from_degrees(lon, lat).unwrap()
+}
+
+fn sample_rect() -> GeoRect {
+    GeoRect::new(coord(170.0, 10.0), coord(-170.0, -10.0)).unwrap()
+}
+
+#[test]
+fn bincode_round_trip() {
+    let paris = coord(2.2945, 48.858222);
+    let bytes = bincode::serialize(&paris).unwrap();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bincode::deserialize::<GeoCoord>(&bytes).unwrap(), paris);
+
+    let rect = sample_rect();
+    let bytes = bincode::serialize(&rect).unwrap();
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bincode::deserialize::<GeoRect>(&bytes).unwrap(), rect);
+
+    let tile_id = TileId::new(12, 31, 5).unwrap();
+    let bytes = bincode::serialize(&tile_id).unwrap();
+    assert_eq!(bytes, [12, 0, 0, 0, 31, 0, 0, 0, 5, 0, 0, 0]);
+    assert_eq!(bincode::deserialize::<TileId>(&bytes).unwrap(), tile_id);
+
+    let compact = CompactGeoCoord::from(paris);
+    let bytes = bincode::serialize(&compact).unwrap();
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(bincode::deserialize::<CompactGeoCoord>(&bytes).unwrap(), compact);
+}
+
+#[test]
+fn bincode_wire_format() {
+    // Longitude first, both as little-endian f64
+    let bytes = bincode::serialize(&coord(1.5, -2.0)).unwrap();
+    assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0xF8, 0x3F, 0, 0, 0, 0, 0, 0, 0, 0xC0]);
+
+    // Top left before bottom right
+    let rect = GeoRect::new(coord(1.5, -2.0), coord(1.5, -2.0)).unwrap();
+    assert_eq!(bincode::serialize(&rect).unwrap(), [&bytes[..], &bytes[..]].concat());
+}
+
+#[test]
+fn bincode_rejects_invalid() {
+    let invalid_coord = bincode::serialize(&(0.0f64, 91.0f64)).unwrap();
+    assert!(bincode::deserialize::<GeoCoord>(&invalid_coord).is_err());
+
+    let inverted_rect = bincode::serialize(&((0.0f64, -10.0f64), (10.0f64, 10.0f64))).unwrap();
+    assert!(bincode::deserialize::<GeoRect>(&inverted_rect).is_err());
+
+    let invalid_tile_id = bincode::serialize(&(32u32, 0u32, 5u32)).unwrap();
+    assert!(bincode::deserialize::<TileId>(&invalid_tile_id).is_err());
+}
+
+#[test]
+fn postcard_round_trip() {
+    let paris = coord(2.2945, 48.858222);
+    let bytes = postcard::to_allocvec(&paris).unwrap();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(postcard::from_bytes::<GeoCoord>(&bytes).unwrap(), paris);
+
+    let rect = sample_rect();
+    let bytes = postcard::to_allocvec(&rect).unwrap();
+    assert_eq!(postcard::from_bytes::<GeoRect>(&bytes).unwrap(), rect);
+
+    // Integers are varint encoded
+    let tile_id = TileId::new(12, 31, 5).unwrap();
+    let bytes = postcard::to_allocvec(&tile_id).unwrap();
+    assert_eq!(bytes, [12, 31, 5]);
+    assert_eq!(postcard::from_bytes::<TileId>(&bytes).unwrap(), tile_id);
+
+    let compact = CompactGeoCoord::from(paris);
+    let bytes = postcard::to_allocvec(&compact).unwrap();
+    assert_eq!(postcard::from_bytes::<CompactGeoCoord>(&bytes).unwrap(), compact);
+
+    let invalid_tile_id = postcard::to_allocvec(&(0u32, 2u32, 1u32)).unwrap();
+    assert!(postcard::from_bytes::<TileId>(&invalid_tile_id).is_err());
+}